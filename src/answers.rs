@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::io;
+use std::path::PathBuf;
+
+use crate::parse::parse_token;
+use crate::{Answer, ParseError, Part};
+
+/// Known-correct answers for one day, keyed by input set name.
+///
+/// Stored as a small TOML file, one table per input set:
+///
+/// ```toml
+/// [input]
+/// part1 = 35
+/// part2 = 46
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    sets: BTreeMap<String, BTreeMap<Part, Answer>>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, ParseError),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => {
+                write!(f, "unable to access {}: {}", path.display(), err)
+            }
+            AnswersError::Invalid(path, err) => write!(f, "{}, {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io(_, err) => Some(err),
+            AnswersError::Invalid(_, err) => Some(err),
+        }
+    }
+}
+
+/// The set name used for the default input of a day.
+pub const DEFAULT_SET: &str = "input";
+
+pub fn answers_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("answers/day{}.toml", day))
+}
+
+impl Answers {
+    /// Loads the answers for `day`; a missing file means nothing is recorded yet.
+    pub fn load(day: u8) -> Result<Self, AnswersError> {
+        let path = answers_path(day);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|err| AnswersError::Invalid(path, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AnswersError::Io(path, err)),
+        }
+    }
+
+    pub fn save(&self, day: u8) -> Result<(), AnswersError> {
+        let path = answers_path(day);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| AnswersError::Io(path.clone(), err))?;
+        }
+        std::fs::write(&path, self.to_string()).map_err(|err| AnswersError::Io(path, err))
+    }
+
+    pub fn sets(&self) -> impl Iterator<Item = &str> {
+        self.sets.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, set: &str, part: Part) -> Option<&Answer> {
+        self.sets.get(set).and_then(|answers| answers.get(&part))
+    }
+
+    pub fn insert(&mut self, set: &str, part: Part, answer: Answer) {
+        self.sets
+            .entry(set.to_owned())
+            .or_default()
+            .insert(part, answer);
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut current = None;
+        for (i, line) in s.lines().enumerate() {
+            parse_line(line, &mut current, &mut answers).map_err(|err| err.on_line(i + 1))?;
+        }
+        Ok(answers)
+    }
+}
+
+fn parse_line(
+    line: &str,
+    current: &mut Option<String>,
+    answers: &mut Answers,
+) -> Result<(), ParseError> {
+    let content = uncomment(line).trim();
+    if content.is_empty() {
+        return Ok(());
+    }
+    if let Some(name) = content.strip_prefix('[') {
+        let name = name
+            .strip_suffix(']')
+            .ok_or_else(|| ParseError::new(line, content, "expected '[<set>]'"))?;
+        *current = Some(name.trim().to_owned());
+        return Ok(());
+    }
+    let (key, value) = content
+        .split_once('=')
+        .ok_or_else(|| ParseError::new(line, content, "expected '<part> = <answer>'"))?;
+    let (key, value) = (key.trim(), value.trim());
+    let part = match key {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _ => return Err(ParseError::new(line, key, "expected 'part1' or 'part2'")),
+    };
+    let set = current
+        .as_deref()
+        .ok_or_else(|| ParseError::new(line, key, "answer outside of a [<set>]"))?;
+    let answer = match value.strip_prefix('"') {
+        Some(text) => Answer::Text(unquote(line, text)?),
+        None => Answer::Integer(parse_token(line, value)?),
+    };
+    answers.insert(set, part, answer);
+    Ok(())
+}
+
+/// The part of `line` before any `#` comment, ignoring `#` inside strings.
+fn uncomment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Reads the rest of a string whose opening quote has been stripped from
+/// `text`, undoing the escapes [`quote`] adds.
+fn unquote(line: &str, text: &str) -> Result<String, ParseError> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '"' if idx + 1 == text.len() => return Ok(value),
+            '"' => {
+                let rest = &text[idx + 1..];
+                return Err(ParseError::new(line, rest, "unexpected text after string"));
+            }
+            '\\' => match chars.next() {
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                _ => {
+                    let escape = &text[idx..(idx + 2).min(text.len())];
+                    return Err(ParseError::new(line, escape, "invalid escape"));
+                }
+            },
+            c => value.push(c),
+        }
+    }
+    Err(ParseError::at_end(line, "unterminated string"))
+}
+
+fn quote(value: &str) -> String {
+    let mut out = String::from('"');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        for (set, answers) in &self.sets {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            writeln!(out, "[{}]", set)?;
+            for (part, answer) in answers {
+                match answer {
+                    Answer::Integer(value) => writeln!(out, "part{} = {}", part, value)?,
+                    Answer::Text(value) => writeln!(out, "part{} = {}", part, quote(value))?,
+                    // there is nothing to check a missing answer against later
+                    Answer::Unsolvable(_) => {}
+                }
+            }
+        }
+        f.write_str(&out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# recorded by aoc verify --record
+[example1]
+part1 = 35
+part2 = \"XJ-9\"
+
+[input]
+part1 = 1234567890123
+";
+
+    #[test]
+    fn parses_sets_and_answers() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        assert_eq!(answers.sets().collect::<Vec<_>>(), ["example1", "input"]);
+        assert_eq!(answers.get("example1", Part::One), Some(&35.into()));
+        assert_eq!(answers.get("example1", Part::Two), Some(&"XJ-9".into()));
+        assert_eq!(
+            answers.get("input", Part::One),
+            Some(&1234567890123i64.into())
+        );
+        assert_eq!(answers.get("input", Part::Two), None);
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn round_trips_awkward_text() {
+        let mut answers = Answers::default();
+        answers.insert("input", Part::One, "a # \"b\"\nc\\".into());
+        let text = answers.to_string();
+        assert_eq!(text, "[input]\npart1 = \"a # \\\"b\\\"\\nc\\\\\"\n");
+        assert_eq!(text.parse::<Answers>().unwrap(), answers);
+        assert_eq!(
+            "[input]\npart1 = \"# kept\" # dropped"
+                .parse::<Answers>()
+                .unwrap()
+                .get("input", Part::One),
+            Some(&"# kept".into())
+        );
+    }
+
+    #[test]
+    fn reports_the_offending_line() {
+        let err = "[input]\npart3 = 1".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line_number(), err.column()), (2, 1));
+    }
+}
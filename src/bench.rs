@@ -0,0 +1,148 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::json::json_string;
+use crate::Part;
+
+/// Wall time summary over a number of runs of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Runs `f` `iterations` times, returning the output of the last run alongside
+/// its timings.
+pub fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> (T, Stats) {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut output = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let result = black_box(f());
+        samples.push(start.elapsed());
+        output = Some(result);
+    }
+    (output.unwrap(), Stats::from_samples(samples))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl Timings {
+    pub fn phases(&self) -> impl Iterator<Item = (String, &Stats)> {
+        std::iter::once(("parse".to_owned(), &self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|(part, stats)| (format!("part {}", part), stats)),
+        )
+    }
+}
+
+pub struct Entry {
+    pub day: u8,
+    pub input: String,
+    pub timings: Timings,
+}
+
+/// Timings for a whole benchmark run, serialisable as JSON for tracking over time.
+pub struct Report {
+    pub iterations: usize,
+    pub entries: Vec<Entry>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        write!(out, "{{\"iterations\":{},\"days\":[", self.iterations).unwrap();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(
+                out,
+                "{{\"day\":{},\"input\":{},\"phases\":[",
+                entry.day,
+                json_string(&entry.input)
+            )
+            .unwrap();
+            for (j, (phase, stats)) in entry.timings.phases().enumerate() {
+                if j > 0 {
+                    out.push(',');
+                }
+                write!(
+                    out,
+                    "{{\"phase\":{},\"min_ns\":{},\"median_ns\":{},\"max_ns\":{}}}",
+                    json_string(&phase),
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+                .unwrap();
+            }
+            out.push_str("]}");
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(5),
+                max: ms(9)
+            }
+        );
+    }
+
+    #[test]
+    fn serialises_report_as_json() {
+        let stats = Stats::from_samples(vec![ms(1)]);
+        let report = Report {
+            iterations: 1,
+            entries: vec![Entry {
+                day: 5,
+                input: "inputs/\"day5\"".to_owned(),
+                timings: Timings {
+                    parse: stats,
+                    parts: vec![(Part::Two, stats)],
+                },
+            }],
+        };
+        assert_eq!(
+            report.to_json(),
+            "{\"iterations\":1,\"days\":[{\"day\":5,\"input\":\"inputs/\\\"day5\\\"\",\"phases\":[\
+             {\"phase\":\"parse\",\"min_ns\":1000000,\"median_ns\":1000000,\"max_ns\":1000000},\
+             {\"phase\":\"part 2\",\"min_ns\":1000000,\"median_ns\":1000000,\"max_ns\":1000000}]}]}"
+        );
+    }
+}
@@ -0,0 +1,495 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc2023::answers::{Answers, AnswersError, DEFAULT_SET};
+use aoc2023::bench::{Entry, Report};
+use aoc2023::days::day7;
+use aoc2023::days::day8::{Day8, Path};
+use aoc2023::{
+    days, Answer, Diagnostic, InputError, InputSource, ParseError, Part, Puzzle, Severity, Solution,
+};
+
+const USAGE: &str =
+    "usage: aoc run <day> [--part <1|2>] [--input <path|-|name>] [--strict] [--explain]
+       aoc run --all [--part <1|2>] [--input <name>] [--strict]
+       aoc run <day>|--all --bench <iterations> [--json <path>] [...]
+       aoc verify [<day>|--all] [--part <1|2>] [--input <name>] [--record] [--strict]
+       aoc check <day>|--all [--input <path|-|name>]
+       aoc run|check 7 --part <1|2> --rules <path> [...]
+       aoc export 8 [--format <dot|json>] [--path <part1|ghosts>] [--input <path|-|name>]";
+
+enum Command {
+    /// Solve, optionally explaining how each answer was reached first.
+    Run { explain: bool },
+    /// Compare against `answers/dayN.toml`, optionally recording new answers.
+    Verify { record: bool },
+    /// Time parsing and each part, optionally writing a JSON report.
+    Bench {
+        iterations: usize,
+        json: Option<PathBuf>,
+    },
+    /// Report anything questionable about the input without solving it.
+    Check,
+    /// Print the day 8 network, optionally with the paths walked through it.
+    Export {
+        format: Format,
+        paths: Option<Paths>,
+    },
+}
+
+enum Format {
+    Dot,
+    Json,
+}
+
+/// Which walks `aoc export` draws over the network.
+enum Paths {
+    Part1,
+    Ghosts,
+}
+
+enum Selection {
+    Day(u8),
+    All,
+}
+
+struct Args {
+    command: Command,
+    selection: Selection,
+    part: Option<Part>,
+    input: InputSource,
+    /// Refuse to solve inputs that have warnings.
+    strict: bool,
+    /// Camel Cards rules to play the selected part of day 7 by instead.
+    rules: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut iter = args.iter();
+    let mut command = match iter.next().map(String::as_str) {
+        Some("run") => Command::Run { explain: false },
+        Some("verify") => Command::Verify { record: false },
+        Some("check") => Command::Check,
+        Some("export") => Command::Export {
+            format: Format::Dot,
+            paths: None,
+        },
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+        None => return Err("missing command".to_owned()),
+    };
+
+    let mut selection = None;
+    let mut part = None;
+    let mut input = InputSource::Default;
+    let mut bench = None;
+    let mut json = None;
+    let mut strict = false;
+    let mut rules = None;
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--all" => selection = Some(Selection::All),
+            "--strict" => strict = true,
+            "--explain" => match &mut command {
+                Command::Run { explain } => *explain = true,
+                _ => return Err("--explain only applies to run".to_owned()),
+            },
+            "--record" => match &mut command {
+                Command::Verify { record } => *record = true,
+                _ => return Err("--record only applies to verify".to_owned()),
+            },
+            "--bench" => {
+                let value = iter.next().ok_or("--bench needs a value")?;
+                bench = match value.parse() {
+                    Ok(iterations) if iterations > 0 => Some(iterations),
+                    _ => return Err(format!("invalid iteration count '{}'", value)),
+                }
+            }
+            "--format" => {
+                let Command::Export { format, .. } = &mut command else {
+                    return Err("--format only applies to export".to_owned());
+                };
+                *format = match iter.next().map(String::as_str) {
+                    Some("dot") => Format::Dot,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("invalid format '{}'", other)),
+                    None => return Err("--format needs a value".to_owned()),
+                }
+            }
+            "--path" => {
+                let Command::Export { paths, .. } = &mut command else {
+                    return Err("--path only applies to export".to_owned());
+                };
+                *paths = match iter.next().map(String::as_str) {
+                    Some("part1") => Some(Paths::Part1),
+                    Some("ghosts") => Some(Paths::Ghosts),
+                    Some(other) => return Err(format!("invalid path '{}'", other)),
+                    None => return Err("--path needs a value".to_owned()),
+                }
+            }
+            "--rules" => {
+                let value = iter.next().ok_or("--rules needs a value")?;
+                rules = Some(PathBuf::from(value));
+            }
+            "--json" => {
+                let value = iter.next().ok_or("--json needs a value")?;
+                json = Some(PathBuf::from(value));
+            }
+            "--part" => {
+                part = match iter.next().map(String::as_str) {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    Some(other) => return Err(format!("invalid part '{}'", other)),
+                    None => return Err("--part needs a value".to_owned()),
+                }
+            }
+            "--input" => {
+                let value = iter.next().ok_or("--input needs a value")?;
+                input = InputSource::from_arg(value);
+            }
+            day => {
+                let day = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+                selection = Some(Selection::Day(day));
+            }
+        }
+    }
+
+    match (&command, bench) {
+        (Command::Run { explain: true }, Some(_)) => {
+            return Err("--explain cannot be combined with --bench".to_owned())
+        }
+        (Command::Run { .. }, Some(iterations)) => command = Command::Bench { iterations, json },
+        (_, Some(_)) => return Err("--bench only applies to run".to_owned()),
+        (_, None) if json.is_some() => return Err("--json needs --bench".to_owned()),
+        (_, None) => {}
+    }
+    if let Command::Export { .. } = command {
+        if !matches!(selection, Some(Selection::Day(8))) {
+            return Err("export only supports day 8".to_owned());
+        }
+        if part.is_some() {
+            return Err("--part does not apply to export".to_owned());
+        }
+    }
+    if strict
+        && matches!(
+            command,
+            Command::Bench { .. } | Command::Check | Command::Export { .. }
+        )
+    {
+        return Err("--strict only applies to run and verify".to_owned());
+    }
+
+    let selection = match command {
+        Command::Verify { .. } => selection.unwrap_or(Selection::All),
+        _ => selection.ok_or("missing day, or --all")?,
+    };
+    if rules.is_some() {
+        if matches!(command, Command::Verify { .. }) {
+            return Err("--rules cannot be used with verify".to_owned());
+        }
+        if !matches!(selection, Selection::Day(7)) {
+            return Err("--rules only applies to day 7".to_owned());
+        }
+        if part.is_none() {
+            return Err("--rules needs --part, for the part whose rules it replaces".to_owned());
+        }
+    }
+    let named_only =
+        matches!(command, Command::Verify { .. }) || matches!(selection, Selection::All);
+    if named_only && matches!(input, InputSource::Path(_) | InputSource::Stdin) {
+        return Err("only a named --input can be used here".to_owned());
+    }
+    Ok(Args {
+        command,
+        selection,
+        part,
+        input,
+        strict,
+        rules,
+    })
+}
+
+/// Reads the rules for `aoc --rules`, reporting failures as the runner does.
+fn load_rules(path: &PathBuf) -> Result<day7::Rules, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+    text.parse()
+        .map_err(|err| format!("{}, {}", path.display(), err))
+}
+
+enum RunError {
+    Input(InputError),
+    Parse(String, ParseError),
+    Answers(AnswersError),
+    Strict(String, Vec<Diagnostic>),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Input(err) => write!(f, "{}", err),
+            RunError::Parse(path, err) => write!(f, "{}, {}", path, err),
+            RunError::Answers(err) => write!(f, "{}", err),
+            RunError::Strict(path, warnings) => {
+                write!(f, "{}, refusing to solve in strict mode:", path)?;
+                for warning in warnings {
+                    write!(f, "\n    {}", warning)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Solves `lines`, read from `input`, refusing inputs with warnings when `strict`.
+fn solve(
+    puzzle: &dyn Puzzle,
+    part: Option<Part>,
+    input: &InputSource,
+    lines: &[String],
+    strict: bool,
+) -> Result<Vec<(Part, Answer)>, RunError> {
+    if strict {
+        let warnings: Vec<Diagnostic> = puzzle
+            .check(lines)
+            .map_err(|err| RunError::Parse(input.describe(puzzle.day()), err))?
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+            .collect();
+        if !warnings.is_empty() {
+            return Err(RunError::Strict(input.describe(puzzle.day()), warnings));
+        }
+    }
+    puzzle
+        .solve(lines, part)
+        .map_err(|err| RunError::Parse(input.describe(puzzle.day()), err))
+}
+
+fn run(
+    puzzle: &dyn Puzzle,
+    part: Option<Part>,
+    input: &InputSource,
+    strict: bool,
+    explain: bool,
+) -> Result<bool, RunError> {
+    // stdin can only be read once, so explaining and solving share the lines
+    let lines = input.read(puzzle.day()).map_err(RunError::Input)?;
+    if explain {
+        let explanation = puzzle
+            .explain(&lines, part)
+            .map_err(|err| RunError::Parse(input.describe(puzzle.day()), err))?;
+        for (part, line) in explanation {
+            println!("day {}, part {}, {}", puzzle.day(), part, line);
+        }
+    }
+    let mut solved = true;
+    for (part, answer) in solve(puzzle, part, input, &lines, strict)? {
+        println!("day {}, part {}: {}", puzzle.day(), part, answer);
+        solved &= !matches!(answer, Answer::Unsolvable(_));
+    }
+    Ok(solved)
+}
+
+fn bench(
+    puzzle: &dyn Puzzle,
+    part: Option<Part>,
+    input: &InputSource,
+    iterations: usize,
+) -> Result<Entry, RunError> {
+    let day = puzzle.day();
+    let lines = input.read(day).map_err(RunError::Input)?;
+    let timings = puzzle
+        .bench(&lines, part, iterations)
+        .map_err(|err| RunError::Parse(input.describe(day), err))?;
+    for (phase, stats) in timings.phases() {
+        println!(
+            "day {}, {:<7} min {:>10.3?}  median {:>10.3?}  max {:>10.3?}",
+            day, phase, stats.min, stats.median, stats.max
+        );
+    }
+    Ok(Entry {
+        day,
+        input: input.describe(day),
+        timings,
+    })
+}
+
+/// Re-solves every recorded input set (or just `input`, if one was named) and
+/// reports each answer as PASS, FAIL or NEW. Inputs without an answer always
+/// FAIL. Returns whether nothing failed.
+fn verify(
+    puzzle: &dyn Puzzle,
+    part: Option<Part>,
+    input: &InputSource,
+    record: bool,
+    strict: bool,
+) -> Result<bool, RunError> {
+    let day = puzzle.day();
+    let mut answers = Answers::load(day).map_err(RunError::Answers)?;
+    let sets: Vec<String> = match input {
+        InputSource::Named(name) => vec![name.clone()],
+        _ => {
+            let mut sets: Vec<String> = answers.sets().map(String::from).collect();
+            let has_default = InputSource::Default.path(day).is_some_and(|p| p.is_file());
+            if has_default && !sets.iter().any(|set| set == DEFAULT_SET) {
+                sets.push(DEFAULT_SET.to_owned());
+            }
+            sets
+        }
+    };
+
+    let mut passed = true;
+    let mut recorded = false;
+    for set in sets {
+        let source = match set.as_str() {
+            DEFAULT_SET => InputSource::Default,
+            name => InputSource::Named(name.to_owned()),
+        };
+        let lines = source.read(day).map_err(RunError::Input)?;
+        for (part, answer) in solve(puzzle, part, &source, &lines, strict)? {
+            let label = format!("day {}, part {} [{}]", day, part, set);
+            match answers.get(&set, part) {
+                Some(expected) if *expected == answer => println!("PASS {}: {}", label, answer),
+                Some(expected) => {
+                    println!("FAIL {}: expected {}, got {}", label, expected, answer);
+                    passed = false;
+                }
+                None if matches!(answer, Answer::Unsolvable(_)) => {
+                    println!("FAIL {}: {}", label, answer);
+                    passed = false;
+                }
+                None => {
+                    println!("NEW  {}: {}", label, answer);
+                    if record {
+                        answers.insert(&set, part, answer);
+                        recorded = true;
+                    }
+                }
+            }
+        }
+    }
+
+    if recorded {
+        answers.save(day).map_err(RunError::Answers)?;
+    }
+    Ok(passed)
+}
+
+/// Prints the day 8 network in `format`, with the chosen walks drawn over it.
+fn export(input: &InputSource, format: &Format, paths: &Option<Paths>) -> Result<bool, RunError> {
+    let day = Day8::DAY;
+    let lines = input.read(day).map_err(RunError::Input)?;
+    let (instructions, graph) = Day8
+        .parse(&lines)
+        .map_err(|err| RunError::Parse(input.describe(day), err))?;
+    let paths: Vec<Path> = match paths {
+        None => vec![],
+        Some(Paths::Part1) => Path::part_1(&instructions, &graph).into_iter().collect(),
+        Some(Paths::Ghosts) => Path::ghosts(&instructions, &graph),
+    };
+    let out = match format {
+        Format::Dot => graph.to_dot(&paths),
+        Format::Json => graph.to_json(&paths),
+    };
+    println!("{}", out.trim_end());
+    Ok(true)
+}
+
+/// Prints every diagnostic for the input. Returns whether there were no warnings.
+fn check(puzzle: &dyn Puzzle, input: &InputSource) -> Result<bool, RunError> {
+    let day = puzzle.day();
+    let lines = input.read(day).map_err(RunError::Input)?;
+    let diagnostics = puzzle
+        .check(&lines)
+        .map_err(|err| RunError::Parse(input.describe(day), err))?;
+    for diagnostic in &diagnostics {
+        println!("day {}, {}", day, diagnostic);
+    }
+    Ok(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.severity < Severity::Warning))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {}\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let variant;
+    let puzzles = match (args.selection, &args.rules) {
+        (_, Some(path)) => {
+            let rules = match load_rules(path) {
+                Ok(rules) => rules,
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    return ExitCode::FAILURE;
+                }
+            };
+            variant = day7::Variant {
+                // parse_args only accepts --rules along with --part
+                part: args.part.unwrap(),
+                rules,
+            };
+            vec![&variant as &dyn Puzzle]
+        }
+        (Selection::All, None) => days::DAYS.to_vec(),
+        (Selection::Day(day), None) => match days::get(day) {
+            Some(puzzle) => vec![puzzle],
+            None => {
+                eprintln!("error: no solution for day {}", day);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut entries = vec![];
+    for puzzle in puzzles {
+        let result = match args.command {
+            Command::Run { explain } => run(puzzle, args.part, &args.input, args.strict, explain),
+            Command::Verify { record } => {
+                verify(puzzle, args.part, &args.input, record, args.strict)
+            }
+            Command::Check => check(puzzle, &args.input),
+            Command::Export {
+                ref format,
+                ref paths,
+            } => export(&args.input, format, paths),
+            Command::Bench { iterations, .. } => bench(puzzle, args.part, &args.input, iterations)
+                .map(|entry| {
+                    entries.push(entry);
+                    true
+                }),
+        };
+        match result {
+            Ok(true) => {}
+            Ok(false) => status = ExitCode::FAILURE,
+            Err(err) => {
+                eprintln!("error: day {}: {}", puzzle.day(), err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Command::Bench {
+        iterations,
+        json: Some(path),
+    } = args.command
+    {
+        let report = Report {
+            iterations,
+            entries,
+        };
+        if let Err(err) = std::fs::write(&path, report.to_json()) {
+            eprintln!("error: unable to write {}: {}", path.display(), err);
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
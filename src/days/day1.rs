@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use crate::{Answer, ParseError, Solution};
+
+/// Lines may only spell out their digits, which `part_1` cannot read, so it
+/// reports the first such line instead of an answer.
+fn part_1(lines: &[String]) -> Result<u32, String> {
+    lines.iter().enumerate().try_fold(0u32, |total, (i, line)| {
+        let mut digits = vec![];
+        for char in line.chars() {
+            if char.is_ascii_digit() {
+                digits.push(char.to_digit(10).unwrap());
+            }
+        }
+        match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => Ok(total + first * 10 + last),
+            _ => Err(format!("line {} has no digit", i + 1)),
+        }
+    })
+}
+
+fn try_parse(word: &str) -> Vec<u32> {
+    let map: HashMap<&str, u32> = HashMap::from([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ]);
+    let mut digits: Vec<(usize, u32)> = vec![];
+    for key in map.keys() {
+        let matches = word.match_indices(key);
+        for (idx, m) in matches {
+            let translation = map.get(m).unwrap();
+            digits.push((idx, *translation));
+        }
+    }
+    digits.sort_by_key(|(i, _)| *i);
+    digits.iter().map(|(_, value)| *value).collect()
+}
+
+fn part_2(lines: &[String]) -> u32 {
+    let mut total = 0;
+
+    for line in lines {
+        let mut current_word: String = String::new();
+        let mut collected_digits: Vec<u32> = vec![];
+
+        for char in line.chars() {
+            if char.is_alphabetic() {
+                current_word.push(char);
+            } else {
+                collected_digits.extend(try_parse(&current_word));
+                current_word.clear();
+                let parsed = char.to_digit(10).unwrap();
+                collected_digits.push(parsed);
+            }
+        }
+        collected_digits.extend(try_parse(&current_word));
+        total += collected_digits.first().unwrap() * 10 + collected_digits.last().unwrap();
+    }
+
+    total
+}
+
+/// Accepts lines of letters and digits with at least one digit, written either
+/// way, so that both parts can read a calibration value from every line.
+fn parse(lines: &[String]) -> Result<Vec<String>, ParseError> {
+    for (i, line) in lines.iter().enumerate() {
+        if let Some((idx, c)) = line
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphanumeric())
+        {
+            let message = "expected only letters and digits";
+            return Err(
+                ParseError::new(line, &line[idx..idx + c.len_utf8()], message).on_line(i + 1),
+            );
+        }
+        if !line.chars().any(|c| c.is_ascii_digit()) && try_parse(line).is_empty() {
+            let message = "expected at least one digit";
+            return Err(ParseError::at_end(line, message).on_line(i + 1));
+        }
+    }
+    Ok(lines.to_vec())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_example, parse_example_error};
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day1, EXAMPLE_1);
+        assert_eq!(Day1.part_1(&input), 142.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day1, EXAMPLE_2);
+        assert_eq!(Day1.part_2(&input), 281.into());
+    }
+
+    #[test]
+    fn rejects_lines_without_a_calibration_value() {
+        let parse = |line: &str| {
+            let err = parse_example_error(&Day1, line);
+            (err.column(), err.message().to_owned())
+        };
+        assert_eq!(
+            parse("ab.1"),
+            (3, "expected only letters and digits".to_owned())
+        );
+        assert_eq!(parse("abc"), (4, "expected at least one digit".to_owned()));
+    }
+
+    #[test]
+    fn part_1_needs_a_digit_on_every_line() {
+        let input = parse_example(&Day1, EXAMPLE_2);
+        assert_eq!(
+            Day1.part_1(&input),
+            Answer::Unsolvable("line 2 has no digit".into())
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::parse::parse_token;
+use crate::{Answer, ParseError, Solution};
+
+struct Turn {
+    reds: u32,
+    greens: u32,
+    blues: u32,
+}
+
+pub struct Game {
+    id: u32,
+    turns: Vec<Turn>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (identifier, round) = value
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at_end(value, "expected ': ' after the game id"))?;
+        let id = identifier
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| ParseError::new(value, identifier, "expected 'Game <id>'"))?;
+        let id = parse_token(value, id)?;
+
+        let turns = round
+            .split("; ")
+            .map(|turn| {
+                let mut reds = 0;
+                let mut greens = 0;
+                let mut blues = 0;
+                for cubes in turn.split(", ") {
+                    let (n, colour) = cubes
+                        .split_once(' ')
+                        .ok_or_else(|| ParseError::new(value, cubes, "expected '<n> <colour>'"))?;
+                    let amount = parse_token(value, n)?;
+                    match colour {
+                        "red" => reds = amount,
+                        "green" => greens = amount,
+                        "blue" => blues = amount,
+                        _ => return Err(ParseError::new(value, colour, "unknown colour")),
+                    }
+                }
+                Ok(Turn {
+                    reds,
+                    greens,
+                    blues,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, turns })
+    }
+}
+
+fn part_1(games: &[Game]) -> u32 {
+    let possible_games = games.iter().filter(|game| {
+        game.turns
+            .iter()
+            .all(|turn| turn.reds <= 12 && turn.greens <= 13 && turn.blues <= 14)
+    });
+    possible_games.fold(0, |sum, game| sum + game.id)
+}
+
+fn part_2(games: &[Game]) -> u32 {
+    let summed_powers = games.iter().fold(0, |acc, game| {
+        let (r, g, b) = game.turns.iter().fold((0, 0, 0), |(r, g, b), turn| {
+            (r.max(turn.reds), g.max(turn.greens), b.max(turn.blues))
+        });
+        acc + r * g * b
+    });
+    summed_powers
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Game::try_from(line.as_str()).map_err(|err| err.on_line(i + 1)))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_example;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day2, EXAMPLE);
+        assert_eq!(Day2.part_1(&input), 8.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day2, EXAMPLE);
+        assert_eq!(Day2.part_2(&input), 2286.into());
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use crate::grid::{Coordinate, Grid};
+use crate::parse::parse_token;
+use crate::{Answer, ParseError, Solution};
+
+struct Number {
+    value: u32,
+    coordinates: Vec<Coordinate>,
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    // for every cell, the index into `numbers` of the number covering it
+    number_at: Grid<Option<usize>>,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+impl Schematic {
+    fn numbers_around(&self, coordinate: Coordinate) -> HashSet<usize> {
+        self.number_at
+            .neighbours_8(coordinate)
+            .filter_map(|(_, number)| *number)
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for Schematic {
+    type Error = ParseError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let grid = Grid::try_from(value)?;
+        let mut numbers = vec![];
+        let mut number_at = grid.map(|_| None);
+        for (y, line) in value.iter().enumerate() {
+            let mut start = None;
+            let cells = line.char_indices().chain([(line.len(), '.')]);
+            for (x, (idx, char)) in cells.enumerate() {
+                match (char.is_ascii_digit(), start) {
+                    (true, None) => start = Some((x, idx)),
+                    (false, Some((start_x, start_idx))) => {
+                        let token = &line[start_idx..idx];
+                        let value = parse_token(line, token).map_err(|err| err.on_line(y + 1))?;
+                        let coordinates: Vec<Coordinate> = (start_x..x)
+                            .map(|x| Coordinate::new(x as i32, y as i32))
+                            .collect();
+                        for coordinate in &coordinates {
+                            number_at[*coordinate] = Some(numbers.len());
+                        }
+                        numbers.push(Number { value, coordinates });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(Schematic {
+            grid,
+            numbers,
+            number_at,
+        })
+    }
+}
+
+fn part_1(schematic: &Schematic) -> u32 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            number.coordinates.iter().any(|coordinate| {
+                schematic
+                    .grid
+                    .neighbours_8(*coordinate)
+                    .any(|(_, cell)| is_symbol(*cell))
+            })
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part_2(schematic: &Schematic) -> u32 {
+    let mut total = 0;
+
+    for (coordinate, cell) in schematic.grid.iter() {
+        if *cell == '*' {
+            let neighbouring_numbers = schematic.numbers_around(coordinate);
+            if neighbouring_numbers.len() == 2 {
+                total += neighbouring_numbers
+                    .iter()
+                    .map(|i| schematic.numbers[*i].value)
+                    .product::<u32>();
+            }
+        }
+    }
+
+    total
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Schematic::try_from(lines)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_example;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day3, EXAMPLE);
+        assert_eq!(Day3.part_1(&input), 4361.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day3, EXAMPLE);
+        assert_eq!(Day3.part_2(&input), 467835.into());
+    }
+}
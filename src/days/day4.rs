@@ -0,0 +1,125 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{header, numbers, parse_token};
+use crate::{Answer, ParseError, Solution};
+
+pub struct ScratchCard {
+    id: usize,
+    numbers: Vec<u8>,
+    winning_numbers: HashSet<u8>,
+}
+
+impl TryFrom<&str> for ScratchCard {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (id_part, rest) = header(value)?;
+        let id = id_part
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| ParseError::new(value, id_part, "expected 'Card <id>'"))?;
+        let id = parse_token(value, id)?;
+
+        let (winning_nums, nums) = rest
+            .split_once('|')
+            .ok_or_else(|| ParseError::at_end(value, "expected '|' between the number lists"))?;
+        let winning_numbers = HashSet::from_iter(numbers(value, winning_nums)?);
+        let numbers = numbers(value, nums)?;
+
+        Ok(ScratchCard {
+            id,
+            numbers,
+            winning_numbers,
+        })
+    }
+}
+
+fn winning_numbers(scratch_card: &ScratchCard) -> usize {
+    scratch_card
+        .numbers
+        .iter()
+        .filter(|num| scratch_card.winning_numbers.contains(*num))
+        .collect::<Vec<_>>()
+        .len()
+}
+
+fn part_1(scratch_cards: &[ScratchCard]) -> u32 {
+    let total = scratch_cards.iter().fold(0, |total, card| {
+        let winning = winning_numbers(card);
+        if winning > 0 {
+            let exponent: u32 = (winning - 1) as u32;
+            let score = 2_u32.pow(exponent);
+            total + score
+        } else {
+            total
+        }
+    });
+    total
+}
+
+fn part_2(scratch_cards: &[ScratchCard]) -> i32 {
+    let mut copies = HashMap::new();
+
+    for card in scratch_cards {
+        let copies_of_current_card = copies.get(&card.id).unwrap_or(&0) + 1;
+        copies.insert(card.id, copies_of_current_card);
+
+        let winning = winning_numbers(card);
+        for i in 1..=winning {
+            let key = card.id + i;
+            let existing_copies = copies.get(&key).unwrap_or(&0);
+            copies.insert(key, existing_copies + copies_of_current_card);
+        }
+    }
+
+    copies.values().sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<ScratchCard>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| ScratchCard::try_from(line.as_str()).map_err(|err| err.on_line(i + 1)))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_example;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day4, EXAMPLE);
+        assert_eq!(Day4.part_1(&input), 13.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day4, EXAMPLE);
+        assert_eq!(Day4.part_2(&input), 30.into());
+    }
+}
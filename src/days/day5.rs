@@ -0,0 +1,951 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::parse::{header, numbers, sections, Section};
+use crate::{Answer, Diagnostic, ParseError, Part, Severity, Solution};
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+struct Mapping {
+    source_start: i64,
+    source_end: i64,
+    offset: i64,
+}
+
+/// An inclusive range of values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    fn shifted(self, offset: i64) -> Self {
+        Interval {
+            start: self.start + offset,
+            end: self.end + offset,
+        }
+    }
+
+    /// Like `shifted`, for an offset that may not fit in an i64 itself, so long
+    /// as the shifted interval does.
+    fn shifted_wide(self, offset: i128) -> Self {
+        Interval {
+            start: (self.start as i128 + offset) as i64,
+            end: (self.end as i128 + offset) as i64,
+        }
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Interval {
+            start: *range.start(),
+            end: *range.end(),
+        }
+    }
+}
+
+impl From<Interval> for RangeInclusive<i64> {
+    fn from(interval: Interval) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+/// Sorts `intervals` and joins any that overlap or touch.
+fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by_key(|interval| interval.start);
+    let mut merged: Vec<Interval> = vec![];
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(interval.end)
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/// The parts of `intervals` that none of `mappings` cover.
+fn uncovered(mappings: &[Mapping], intervals: Vec<Interval>) -> Vec<Interval> {
+    mappings.iter().fold(intervals, |intervals, mapping| {
+        intervals
+            .into_iter()
+            .flat_map(|interval| mapping.split(interval).1)
+            .collect()
+    })
+}
+
+impl Mapping {
+    fn map(&self, key: &i64) -> Option<i64> {
+        if key < &self.source_start || key > &self.source_end {
+            None
+        } else {
+            Some(key + self.offset)
+        }
+    }
+
+    /// Splits `interval` into the part this mapping covers and the parts
+    /// either side of it that it does not.
+    fn split(&self, interval: Interval) -> (Option<Interval>, Vec<Interval>) {
+        let start = interval.start.max(self.source_start);
+        let end = interval.end.min(self.source_end);
+        if start > end {
+            return (None, vec![interval]);
+        }
+        let mut rest = vec![];
+        if interval.start < start {
+            rest.push(Interval {
+                start: interval.start,
+                end: start - 1,
+            });
+        }
+        if end < interval.end {
+            rest.push(Interval {
+                start: end + 1,
+                end: interval.end,
+            });
+        }
+        (Some(Interval { start, end }), rest)
+    }
+}
+
+/// One `<source>-to-<destination> map:` section of the almanac.
+struct ElfAlmanacMap {
+    source: String,
+    destination: String,
+    /// Where the header sits in the input; mappings follow on the lines after.
+    line_number: usize,
+    mappings: Vec<Mapping>,
+}
+
+impl ElfAlmanacMap {
+    fn get_mapping(&self, key: &i64) -> i64 {
+        for mapping in &self.mappings {
+            if let Some(value) = mapping.map(key) {
+                return value;
+            }
+        }
+        *key
+    }
+
+    /// Splits `interval` into pieces that are each moved by a single offset.
+    /// As with `get_mapping`, the first mapping covering a value wins, and
+    /// uncovered values keep an offset of zero.
+    fn split(&self, interval: Interval) -> Vec<(Interval, i64)> {
+        let mut pieces = vec![];
+        let mut unmapped = vec![interval];
+        for mapping in &self.mappings {
+            let mut remaining = vec![];
+            for interval in unmapped {
+                let (covered, rest) = mapping.split(interval);
+                pieces.extend(covered.map(|covered| (covered, mapping.offset)));
+                remaining.extend(rest);
+            }
+            unmapped = remaining;
+        }
+        pieces.extend(unmapped.into_iter().map(|interval| (interval, 0)));
+        pieces
+    }
+
+    /// Maps every value in `interval` at once.
+    fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        self.split(interval)
+            .into_iter()
+            .map(|(interval, offset)| interval.shifted(offset))
+            .collect()
+    }
+
+    /// Reports mappings that are empty, negative or shadowed by an earlier
+    /// overlapping mapping, and notes the values between mappings that fall
+    /// through unchanged.
+    fn validate(&self) -> Vec<Diagnostic> {
+        let name = format!("{}-to-{}", self.source, self.destination);
+        let diagnostic = |severity, idx: usize, message: String| Diagnostic {
+            severity,
+            line_number: self.line_number + 1 + idx,
+            message: format!("{}: {}", name, message),
+        };
+
+        let mut diagnostics = vec![];
+        for (idx, mapping) in self.mappings.iter().enumerate() {
+            let len = mapping.source_end as i128 - mapping.source_start as i128 + 1;
+            let destination_start = mapping.source_start + mapping.offset;
+            if len == 0 {
+                let message = "mapping covers no values".to_owned();
+                diagnostics.push(diagnostic(Severity::Warning, idx, message));
+            } else if len < 0 || mapping.source_start < 0 || destination_start < 0 {
+                let message = "mapping has a negative start or length".to_owned();
+                diagnostics.push(diagnostic(Severity::Warning, idx, message));
+            }
+            for (earlier_idx, earlier) in self.mappings[..idx].iter().enumerate() {
+                let start = mapping.source_start.max(earlier.source_start);
+                let end = mapping.source_end.min(earlier.source_end);
+                if start <= end {
+                    let message = format!(
+                        "{}..={} overlaps line {}, which takes precedence",
+                        start,
+                        end,
+                        self.line_number + 1 + earlier_idx
+                    );
+                    diagnostics.push(diagnostic(Severity::Warning, idx, message));
+                }
+            }
+        }
+
+        let covered = merge(
+            self.mappings
+                .iter()
+                .filter(|mapping| mapping.source_start <= mapping.source_end)
+                .map(|mapping| Interval {
+                    start: mapping.source_start,
+                    end: mapping.source_end,
+                })
+                .collect(),
+        );
+        for pair in covered.windows(2) {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Note,
+                line_number: self.line_number,
+                message: format!(
+                    "{}: {}..={} is not mapped and passes through unchanged",
+                    name,
+                    pair[0].end.saturating_add(1),
+                    pair[1].start.saturating_sub(1)
+                ),
+            });
+        }
+        diagnostics
+    }
+
+    /// The inverse of `map_interval`: every source value that maps into
+    /// `interval`, taking into account that earlier mappings shadow later ones.
+    fn preimage(&self, interval: Interval) -> Vec<Interval> {
+        let mut sources = uncovered(&self.mappings, vec![interval]);
+        for (idx, mapping) in self.mappings.iter().enumerate() {
+            // shifting back can leave the i64 range, but only the part that
+            // lands within the mapping matters, and that always fits
+            let offset = mapping.offset as i128;
+            let start = (interval.start as i128 - offset).max(mapping.source_start as i128);
+            let end = (interval.end as i128 - offset).min(mapping.source_end as i128);
+            if start <= end {
+                let covered = Interval {
+                    start: start as i64,
+                    end: end as i64,
+                };
+                sources.extend(uncovered(&self.mappings[..idx], vec![covered]));
+            }
+        }
+        merge(sources)
+    }
+}
+
+/// A chain of maps, each converting from the category the one before it
+/// converts to. Every category appears in the chain at most once.
+pub struct ElfAlmanac {
+    maps: Vec<ElfAlmanacMap>,
+}
+
+impl ElfAlmanac {
+    /// Every category in the chain, in order.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        let first = self.maps.first().map(|map| map.source.as_str());
+        first
+            .into_iter()
+            .chain(self.maps.iter().map(|map| map.destination.as_str()))
+    }
+
+    /// The consecutive maps that lead from `from` to `to`, if the chain
+    /// reaches `to` from `from`.
+    fn stages(&self, from: &str, to: &str) -> Option<&[ElfAlmanacMap]> {
+        if from == to {
+            return self
+                .categories()
+                .any(|category| category == from)
+                .then_some(&[]);
+        }
+        let start = self.maps.iter().position(|map| map.source == from)?;
+        let len = self.maps[start..]
+            .iter()
+            .position(|map| map.destination == to)?;
+        Some(&self.maps[start..=start + len])
+    }
+
+    /// Converts `value` from category `from` to category `to`, or `None` if
+    /// the chain does not lead from one to the other.
+    pub fn convert(&self, from: &str, to: &str, value: i64) -> Option<i64> {
+        let stages = self.stages(from, to)?;
+        Some(
+            stages
+                .iter()
+                .fold(value, |value, stage| stage.get_mapping(&value)),
+        )
+    }
+
+    fn convert_intervals(
+        &self,
+        from: &str,
+        to: &str,
+        intervals: &[Interval],
+    ) -> Option<Vec<Interval>> {
+        let stages = self.stages(from, to)?;
+        Some(stages.iter().fold(intervals.to_vec(), |intervals, stage| {
+            intervals
+                .into_iter()
+                .flat_map(|interval| stage.map_interval(interval))
+                .collect()
+        }))
+    }
+
+    /// Every value of category `from` that converts into `range` of category
+    /// `to`, as sorted, disjoint ranges; `None` if the chain does not lead
+    /// from one to the other.
+    pub fn sources(
+        &self,
+        from: &str,
+        to: &str,
+        range: RangeInclusive<i64>,
+    ) -> Option<Vec<RangeInclusive<i64>>> {
+        let stages = self.stages(from, to)?;
+        let sources = stages
+            .iter()
+            .rev()
+            .fold(vec![range.into()], |intervals, stage| {
+                merge(
+                    intervals
+                        .into_iter()
+                        .flat_map(|interval| stage.preimage(interval))
+                        .collect(),
+                )
+            });
+        Some(sources.into_iter().map(RangeInclusive::from).collect())
+    }
+
+    /// Checks every map in the chain for ambiguous or suspicious mappings.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.maps.iter().flat_map(ElfAlmanacMap::validate).collect()
+    }
+
+    /// Collapses the maps leading from `from` to `to` into one, or `None` if
+    /// the chain does not lead from one to the other.
+    pub fn compose(&self, from: &str, to: &str) -> Option<ComposedMap> {
+        let stages = self.stages(from, to)?;
+        // start from the identity over every value, then push each piece
+        // through the stages, splitting it wherever a stage's mappings do
+        let everything = Interval {
+            start: i64::MIN,
+            end: i64::MAX,
+        };
+        let mut pieces = stages.iter().fold(vec![(everything, 0)], |pieces, stage| {
+            pieces
+                .into_iter()
+                .flat_map(|(interval, offset): (Interval, i128)| {
+                    // every image fits in an i64, but across several stages
+                    // the distance travelled to reach it might not
+                    stage.split(interval.shifted_wide(offset)).into_iter().map(
+                        move |(image, next)| (image.shifted_wide(-offset), offset + next as i128),
+                    )
+                })
+                .collect()
+        });
+        pieces.sort_by_key(|(interval, _)| interval.start);
+
+        let mut mappings: Vec<Piece> = vec![];
+        for (interval, offset) in pieces.into_iter().filter(|(_, offset)| *offset != 0) {
+            match mappings.last_mut() {
+                Some(last)
+                    if last.offset == offset
+                        && last.source_end.checked_add(1) == Some(interval.start) =>
+                {
+                    last.source_end = interval.end
+                }
+                _ => mappings.push(Piece {
+                    source_start: interval.start,
+                    source_end: interval.end,
+                    offset,
+                }),
+            }
+        }
+        Some(ComposedMap {
+            source: from.to_owned(),
+            destination: to.to_owned(),
+            mappings,
+        })
+    }
+}
+
+/// Several maps collapsed into one. Its mappings are sorted and disjoint, and
+/// every value outside them maps to itself.
+pub struct ComposedMap {
+    source: String,
+    destination: String,
+    mappings: Vec<Piece>,
+}
+
+/// A mapping within a [`ComposedMap`]. Like a [`Mapping`], but with an offset
+/// that can be wider than an i64, as it adds up the offsets of every stage.
+struct Piece {
+    source_start: i64,
+    source_end: i64,
+    offset: i128,
+}
+
+impl ComposedMap {
+    pub fn get(&self, value: i64) -> i64 {
+        let idx = self
+            .mappings
+            .partition_point(|mapping| mapping.source_end < value);
+        match self.mappings.get(idx) {
+            Some(mapping) if mapping.source_start <= value => {
+                (value as i128 + mapping.offset) as i64
+            }
+            _ => value,
+        }
+    }
+}
+
+/// Prints the map as an almanac section, so that it can be read back in.
+impl fmt::Display for ComposedMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.source, self.destination)?;
+        for mapping in &self.mappings {
+            write!(
+                f,
+                "\n{} {} {}",
+                mapping.source_start as i128 + mapping.offset,
+                mapping.source_start,
+                mapping.source_end as i128 - mapping.source_start as i128 + 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_mapping(line: &str) -> Result<Mapping, ParseError> {
+    let [destination_start, source_start, len] = numbers::<i64>(line, line)?[..] else {
+        return Err(ParseError::new(
+            line,
+            line,
+            "expected '<destination> <source> <length>'",
+        ));
+    };
+    // every value the mapping touches has to fit, or mapping it would wrap
+    let last = len.checked_sub(1);
+    let source_end = last.and_then(|last| source_start.checked_add(last));
+    let destination_end = last.and_then(|last| destination_start.checked_add(last));
+    match (
+        destination_start.checked_sub(source_start),
+        source_end,
+        destination_end,
+    ) {
+        (Some(offset), Some(source_end), Some(_)) => Ok(Mapping {
+            source_start,
+            source_end,
+            offset,
+        }),
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "range overflows a 64-bit integer",
+        )),
+    }
+}
+
+fn parse_header(line: &str) -> Result<(&str, &str), ParseError> {
+    let (key, values) = header(line)?;
+    if !values.is_empty() {
+        return Err(ParseError::new(
+            line,
+            values,
+            "unexpected values after the header",
+        ));
+    }
+    key.strip_suffix(" map")
+        .and_then(|name| name.split_once("-to-"))
+        .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+        .ok_or_else(|| ParseError::new(line, key, "expected '<source>-to-<destination> map:'"))
+}
+
+/// Checks that a map from `source` to `destination` carries on from the end of
+/// `chain` without returning to a category already in it.
+fn link(
+    line: &str,
+    (source, destination): (&str, &str),
+    chain: &[ElfAlmanacMap],
+) -> Result<(), ParseError> {
+    if let Some(previous) = chain.last() {
+        if previous.destination != source {
+            let message = format!("expected a map from '{}'", previous.destination);
+            return Err(ParseError::new(line, source, message));
+        }
+    }
+    let mut categories = chain.iter().map(|map| map.source.as_str()).chain([source]);
+    if categories.any(|category| category == destination) {
+        let message = format!("'{}' already appears earlier in the chain", destination);
+        return Err(ParseError::new(line, destination, message));
+    }
+    Ok(())
+}
+
+fn parse_map(section: &Section, chain: &[ElfAlmanacMap]) -> Result<ElfAlmanacMap, ParseError> {
+    // the first line is the `x-to-y map:` header, the rest are mappings
+    let line = &section.lines[0];
+    let (source, destination) = parse_header(line)
+        .and_then(|names| link(line, names, chain).map(|_| names))
+        .map_err(|err| err.on_line(1).offset_by(section.offset))?;
+    let mappings = section.lines[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_mapping(line).map_err(|err| err.on_line(i + 2)))
+        .collect::<Result<_, _>>()
+        .map_err(|err| err.offset_by(section.offset))?;
+    Ok(ElfAlmanacMap {
+        source: source.to_owned(),
+        destination: destination.to_owned(),
+        line_number: section.offset + 1,
+        mappings,
+    })
+}
+
+/// Parses the maps that follow the `seeds` section.
+fn parse(seeds: &Section, sections: &[Section]) -> Result<ElfAlmanac, ParseError> {
+    let mut maps = vec![];
+    for section in sections {
+        let map = parse_map(section, &maps)?;
+        maps.push(map);
+    }
+    let almanac = ElfAlmanac { maps };
+
+    if almanac.stages(SEED, LOCATION).is_none() {
+        let message = format!("expected maps leading from '{}' to '{}'", SEED, LOCATION);
+        let last = sections.last().unwrap_or(seeds);
+        return Err(ParseError::at_end(last.lines.last().unwrap(), message)
+            .on_line(last.offset + last.lines.len()));
+    }
+    Ok(almanac)
+}
+
+fn parse_seeds(line: &str) -> Result<Vec<i64>, ParseError> {
+    let (_, _seeds) = header(line)?;
+    let seeds: Vec<i64> = numbers(line, _seeds)?;
+    if seeds.is_empty() {
+        return Err(ParseError::at_end(line, "expected at least one seed"));
+    }
+    Ok(seeds)
+}
+
+/// The seeds read as start and length pairs, which only part 2 needs.
+fn seeds_2(seeds: &[i64]) -> Result<Vec<Interval>, String> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(format!(
+            "{} seeds do not make start and length pairs",
+            seeds.len()
+        ));
+    }
+    let intervals = seeds
+        .chunks_exact(2)
+        .filter(|pair| pair[1] > 0)
+        .map(|pair| match pair[0].checked_add(pair[1] - 1) {
+            Some(end) => Ok(Interval {
+                start: pair[0],
+                end,
+            }),
+            None => Err(format!(
+                "seed range {} {} overflows a 64-bit integer",
+                pair[0], pair[1]
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if intervals.is_empty() {
+        return Err("no seed range has a positive length".to_owned());
+    }
+    Ok(intervals)
+}
+
+fn calculate(seeds: &[i64], almanac: &ElfAlmanac) -> i64 {
+    let composed = almanac.compose(SEED, LOCATION).unwrap();
+    seeds.iter().map(|seed| composed.get(*seed)).min().unwrap()
+}
+
+fn calculate_intervals(seeds: &[Interval], almanac: &ElfAlmanac) -> i64 {
+    almanac
+        .convert_intervals(SEED, LOCATION, seeds)
+        .unwrap()
+        .iter()
+        .map(|interval| interval.start)
+        .min()
+        .unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Vec<i64>, ElfAlmanac);
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        let sections = sections(lines);
+        let (section, maps) = sections
+            .split_first()
+            .ok_or_else(|| ParseError::at_end("", "expected seeds").on_line(1))?;
+        let seeds = parse_seeds(&section.lines[0])
+            .map_err(|err| err.on_line(1).offset_by(section.offset))?;
+        Ok((seeds, parse(section, maps)?))
+    }
+
+    fn part_1(&self, (seeds, almanac): &Self::Input) -> Answer {
+        calculate(seeds, almanac).into()
+    }
+
+    fn part_2(&self, (seeds, almanac): &Self::Input) -> Answer {
+        seeds_2(seeds)
+            .map(|seeds| calculate_intervals(&seeds, almanac))
+            .into()
+    }
+
+    fn check(&self, (_, almanac): &Self::Input) -> Vec<Diagnostic> {
+        almanac.validate()
+    }
+
+    /// Both parts look locations up in the same seed-to-location map, so
+    /// either prints it as one composed almanac section.
+    fn explain(&self, (_, almanac): &Self::Input, _part: Part) -> Vec<String> {
+        almanac
+            .compose(SEED, LOCATION)
+            .map(|composed| composed.to_string().lines().map(String::from).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_example, parse_example_error};
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn leaves_part_1_to_seed_ranges_part_2_cannot_use() {
+        let input = parse_example(&Day5, &EXAMPLE.replacen("79 14 55 13", "79 0 55 13", 1));
+        assert_eq!(Day5.part_1(&input), 22.into());
+        assert_eq!(Day5.part_2(&input), 56.into());
+
+        let seeds = "seeds: 9223372036854775800 100";
+        let input = parse_example(&Day5, &EXAMPLE.replacen("seeds: 79 14 55 13", seeds, 1));
+        assert_eq!(Day5.part_1(&input), 100.into());
+        assert_eq!(
+            Day5.part_2(&input),
+            Answer::Unsolvable(
+                "seed range 9223372036854775800 100 overflows a 64-bit integer".into()
+            )
+        );
+        let input = parse_example(&Day5, &EXAMPLE.replacen("79 14 55 13", "79 0", 1));
+        assert_eq!(
+            Day5.part_2(&input),
+            Answer::Unsolvable("no seed range has a positive length".into())
+        );
+    }
+
+    #[test]
+    fn finds_sources_of_ranges_at_the_i64_limits() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        let sources = almanac.sources(SEED, LOCATION, 0..=i64::MAX).unwrap();
+        assert_eq!(sources, [0..=i64::MAX]);
+        let sources = almanac.sources(SEED, LOCATION, i64::MIN..=-1).unwrap();
+        assert_eq!(sources, [i64::MIN..=-1]);
+    }
+
+    #[test]
+    fn points_at_the_seeds_when_there_are_no_maps() {
+        let err = parse_example_error(&Day5, "seeds: 1 2");
+        assert_eq!((err.line_number(), err.column()), (1, 11));
+    }
+
+    #[test]
+    fn rejects_missing_seeds() {
+        let err = parse_example_error(&Day5, &EXAMPLE.replacen("seeds: 79 14 55 13", "seeds:", 1));
+        assert_eq!((err.line_number(), err.column()), (1, 7));
+    }
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day5, EXAMPLE);
+        assert_eq!(Day5.part_1(&input), 35.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day5, EXAMPLE);
+        assert_eq!(Day5.part_2(&input), 46.into());
+    }
+
+    #[test]
+    fn solves_part_1_when_the_seeds_are_not_pairs() {
+        let input = parse_example(&Day5, &EXAMPLE.replacen("79 14 55 13", "79 14 55", 1));
+        assert_eq!(Day5.part_1(&input), 43.into());
+        assert_eq!(
+            Day5.part_2(&input),
+            Answer::Unsolvable("3 seeds do not make start and length pairs".into())
+        );
+    }
+
+    #[test]
+    fn interval_mapping_matches_pointwise_mapping() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        let interval = Interval { start: 0, end: 120 };
+        for stage in [&almanac.maps[0], &almanac.maps[2]] {
+            let mut by_interval: Vec<i64> = stage
+                .map_interval(interval)
+                .iter()
+                .flat_map(|mapped| mapped.start..=mapped.end)
+                .collect();
+            let mut pointwise: Vec<i64> = (0..=120).map(|key| stage.get_mapping(&key)).collect();
+            by_interval.sort();
+            pointwise.sort();
+            assert_eq!(by_interval, pointwise);
+        }
+    }
+
+    #[test]
+    fn converts_between_any_two_categories() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        assert_eq!(almanac.convert("seed", "humidity", 79), Some(78));
+        assert_eq!(almanac.convert("soil", "light", 81), Some(74));
+        assert_eq!(almanac.convert("water", "water", 81), Some(81));
+        assert_eq!(almanac.convert("humidity", "seed", 78), None);
+        assert_eq!(almanac.convert("seed", "weather", 79), None);
+    }
+
+    #[test]
+    fn follows_the_chain_named_in_the_headers() {
+        let input = parse_example(
+            &Day5,
+            "\
+seeds: 1 2
+
+seed-to-water map:
+10 1 2
+
+water-to-location map:
+0 10 1",
+        );
+        assert_eq!(
+            input.1.categories().collect::<Vec<_>>(),
+            ["seed", "water", "location"]
+        );
+        assert_eq!(Day5.part_1(&input), 0.into());
+    }
+
+    #[test]
+    fn rejects_maps_that_do_not_link_up() {
+        let err = parse_example_error(
+            &Day5,
+            &EXAMPLE.replace("fertilizer-to-water", "soil-to-water"),
+        );
+        assert_eq!((err.line_number(), err.column()), (12, 1));
+        assert_eq!(err.message(), "expected a map from 'fertilizer'");
+    }
+
+    #[test]
+    fn finds_the_seeds_that_reach_a_location() {
+        let (seeds, almanac) = parse_example(&Day5, EXAMPLE);
+        let sources = almanac.sources("seed", "location", 46..=46).unwrap();
+        assert_eq!(sources, [82..=82]);
+        // the lowest location from part 2 must come from one of the seed ranges
+        let lowest = calculate_intervals(&seeds_2(&seeds).unwrap(), &almanac);
+        let sources = almanac
+            .sources("seed", "location", lowest..=lowest)
+            .unwrap();
+        let reachable = seeds_2(&seeds).unwrap().iter().any(|seeds| {
+            sources
+                .iter()
+                .any(|range| *range.start() <= seeds.end && seeds.start <= *range.end())
+        });
+        assert!(reachable);
+    }
+
+    #[test]
+    fn preimage_matches_pointwise_mapping() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        let locations = 30..=60;
+        let sources = almanac
+            .sources("seed", "location", locations.clone())
+            .unwrap();
+        for seed in -20..=150 {
+            let location = almanac.convert("seed", "location", seed).unwrap();
+            let found = sources.iter().any(|range| range.contains(&seed));
+            assert_eq!(found, locations.contains(&location), "seed {}", seed);
+        }
+        assert!(sources
+            .windows(2)
+            .all(|pair| pair[0].end() + 1 < *pair[1].start()));
+    }
+
+    #[test]
+    fn explains_with_the_composed_map() {
+        let input = parse_example(&Day5, EXAMPLE);
+        let explanation = Day5.explain(&input, Part::Two);
+        assert_eq!(explanation[0], "seed-to-location map:");
+        let composed = input.1.compose(SEED, LOCATION).unwrap();
+        assert_eq!(explanation.join("\n"), composed.to_string());
+    }
+
+    #[test]
+    fn composed_map_matches_the_chain() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        let composed = almanac.compose("seed", "location").unwrap();
+        for seed in -20..=150 {
+            let expected = almanac.convert("seed", "location", seed).unwrap();
+            assert_eq!(composed.get(seed), expected, "seed {}", seed);
+        }
+        assert!(composed
+            .mappings
+            .windows(2)
+            .all(|pair| pair[0].source_end < pair[1].source_start));
+    }
+
+    #[test]
+    fn validates_mappings_at_the_i64_limit() {
+        let input = parse_example(
+            &Day5,
+            "\
+seeds: 0 1
+
+seed-to-location map:
+0 9223372036854775806 2
+5 9223372036854775807 1",
+        );
+        let diagnostics: Vec<String> = Day5.check(&input).iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            diagnostics,
+            ["warning: line 5: seed-to-location: \
+              9223372036854775807..=9223372036854775807 overlaps line 4, which takes precedence"]
+        );
+    }
+
+    #[test]
+    fn composes_offsets_of_opposite_sign() {
+        let input = parse_example(
+            &Day5,
+            "\
+seeds: 0 1
+
+seed-to-soil map:
+0 -4000000000000000000 5000000000000000000
+
+soil-to-location map:
+9000000000000000000 0 10
+-9000000000000000000 10 10",
+        );
+        let (seeds, almanac) = &input;
+        let composed = almanac.compose(SEED, LOCATION).unwrap();
+        for seed in [
+            -4_000_000_000_000_000_000,
+            -3_999_999_999_999_999_990,
+            0,
+            i64::MIN,
+        ] {
+            let expected = almanac.convert(SEED, LOCATION, seed).unwrap();
+            assert_eq!(composed.get(seed), expected, "seed {}", seed);
+        }
+        assert_eq!(
+            composed.get(-4_000_000_000_000_000_000),
+            9_000_000_000_000_000_000
+        );
+        assert_eq!(calculate(seeds, almanac), 4_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn prints_composed_map_as_a_section() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        let composed = almanac.compose("seed", "soil").unwrap();
+        assert_eq!(composed.to_string(), "seed-to-soil map:\n52 50 48\n50 98 2");
+    }
+
+    #[test]
+    fn example_has_nothing_to_warn_about() {
+        let (_, almanac) = parse_example(&Day5, EXAMPLE);
+        assert!(almanac
+            .validate()
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Note));
+    }
+
+    #[test]
+    fn reports_suspicious_mappings() {
+        let (_, almanac) = parse_example(
+            &Day5,
+            "\
+seeds: 1 2
+
+seed-to-soil map:
+0 10 5
+20 12 5
+0 30 0
+5 40 2
+-1 50 2
+
+soil-to-location map:
+0 0 1",
+        );
+        let found: Vec<_> = almanac
+            .validate()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.severity, diagnostic.line_number))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (Severity::Warning, 5),
+                (Severity::Warning, 6),
+                (Severity::Warning, 8),
+                (Severity::Note, 3),
+                (Severity::Note, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_ranges_that_overflow() {
+        let err = parse_example_error(
+            &Day5,
+            &EXAMPLE.replace("50 98 2", "50 9223372036854775807 2"),
+        );
+        assert_eq!(err.line_number(), 4);
+    }
+}
@@ -0,0 +1,230 @@
+use std::ops::RangeInclusive;
+
+use num::integer::Roots;
+
+use crate::parse::{header, numbers};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Race {
+    duration: i128,
+    distance: i128,
+}
+
+impl Race {
+    fn travelled(&self, hold: i128) -> i128 {
+        hold * (self.duration - hold)
+    }
+
+    /// The hold times that beat the record, found from the roots of
+    /// `hold * (duration - hold) = distance`. The integer square root puts the
+    /// estimate within a step of the true boundary, which is then settled
+    /// exactly.
+    pub fn winning_hold_times(&self) -> Option<RangeInclusive<i128>> {
+        if self.duration < 0 {
+            return None;
+        }
+        let discriminant = self.duration * self.duration - 4 * self.distance;
+        if discriminant <= 0 {
+            return None;
+        }
+        let mut lowest = ((self.duration - discriminant.sqrt()) / 2).max(0);
+        while lowest > 0 && self.travelled(lowest - 1) > self.distance {
+            lowest -= 1;
+        }
+        while lowest <= self.duration && self.travelled(lowest) <= self.distance {
+            lowest += 1;
+        }
+        // the distance travelled is symmetric around half the duration
+        let highest = self.duration - lowest;
+        (lowest <= highest).then_some(lowest..=highest)
+    }
+}
+
+/// Rejects races whose discriminant would not fit in an i128.
+fn check_size(line: &str, values: &str, race: &Race) -> Result<(), ParseError> {
+    let fits = race
+        .duration
+        .checked_mul(race.duration)
+        .zip(race.distance.checked_mul(4))
+        .is_some_and(|(square, distance)| square.checked_sub(distance).is_some());
+    if fits {
+        Ok(())
+    } else {
+        Err(ParseError::new(line, values, "race is too long to solve"))
+    }
+}
+
+fn values(input: &[String], index: usize) -> Result<(&str, &str), ParseError> {
+    let line = input.get(index).map(String::as_str).unwrap_or_default();
+    header(line)
+        .map(|(_, values)| (line, values))
+        .map_err(|err| err.on_line(index + 1))
+}
+
+fn parse_1(input: &[String]) -> Result<Vec<Race>, ParseError> {
+    let mut rows = [0, 1].into_iter().map(|index| {
+        let (line, values) = values(input, index)?;
+        numbers::<i128>(line, values).map_err(|err| err.on_line(index + 1))
+    });
+    let times = rows.next().unwrap()?;
+    let distances = rows.next().unwrap()?;
+    if times.len() != distances.len() {
+        let (line, values) = values(input, 1)?;
+        let message = format!(
+            "expected {} distances, found {}",
+            times.len(),
+            distances.len()
+        );
+        return Err(ParseError::new(line, values, message).on_line(2));
+    }
+    let races: Vec<Race> = times
+        .into_iter()
+        .zip(distances)
+        .map(|(duration, distance)| Race { duration, distance })
+        .collect();
+    let (line, values) = values(input, 0)?;
+    for race in &races {
+        check_size(line, values, race).map_err(|err| err.on_line(1))?;
+    }
+    Ok(races)
+}
+
+fn parse_2(input: &[String]) -> Result<Vec<Race>, ParseError> {
+    let mut rows = [0, 1].into_iter().map(|index| {
+        let (line, values) = values(input, index)?;
+        values
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .parse::<i128>()
+            .map_err(|err| {
+                let message = format!("invalid value '{}': {}", values, err);
+                ParseError::new(line, values, message).on_line(index + 1)
+            })
+    });
+    let duration = rows.next().unwrap()?;
+    let distance = rows.next().unwrap()?;
+    let race = Race { duration, distance };
+    let (line, values) = values(input, 0)?;
+    check_size(line, values, &race).map_err(|err| err.on_line(1))?;
+    Ok(vec![race])
+}
+
+fn do_the_thing(races: &[Race]) -> Result<i128, &'static str> {
+    races.iter().try_fold(1i128, |acc, cur| {
+        let ways = cur
+            .winning_hold_times()
+            .map_or(0, |hold| hold.end() - hold.start() + 1);
+        acc.checked_mul(ways)
+            .ok_or("the product of the ways to win overflows a 128-bit integer")
+    })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    /// The part 2 race can be too big even where the part 1 races are fine,
+    /// so it only fails part 2.
+    type Input = (Vec<Race>, Result<Vec<Race>, ParseError>);
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok((parse_1(lines)?, parse_2(lines)))
+    }
+
+    fn part_1(&self, (races, _): &Self::Input) -> Answer {
+        do_the_thing(races).into()
+    }
+
+    fn part_2(&self, (_, races): &Self::Input) -> Answer {
+        races
+            .as_ref()
+            .map_err(ParseError::message)
+            .and_then(|races| do_the_thing(races))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_example;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day6, EXAMPLE);
+        assert_eq!(Day6.part_1(&input), 288.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day6, EXAMPLE);
+        assert_eq!(Day6.part_2(&input), 71503.into());
+    }
+
+    #[test]
+    fn finds_the_winning_hold_times() {
+        let (races, _) = parse_example(&Day6, EXAMPLE);
+        let winning: Vec<_> = races.iter().map(Race::winning_hold_times).collect();
+        assert_eq!(winning, [Some(2..=5), Some(4..=11), Some(11..=19)]);
+    }
+
+    #[test]
+    fn matches_brute_force_at_the_boundaries() {
+        for duration in 0..60 {
+            for distance in -3..=duration * duration / 4 + 2 {
+                let race = Race { duration, distance };
+                let wins: Vec<i128> = (0..=duration)
+                    .filter(|hold| race.travelled(*hold) > distance)
+                    .collect();
+                let expected = wins.first().map(|first| *first..=*wins.last().unwrap());
+                assert_eq!(
+                    race.winning_hold_times(),
+                    expected,
+                    "{} {}",
+                    duration,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_products_too_big_for_an_i128() {
+        let race = || Race {
+            duration: 10_000_000_000_000_000_000,
+            distance: 0,
+        };
+        assert!(do_the_thing(&[race(), race()]).is_ok());
+        assert!(do_the_thing(&[race(), race(), race()]).is_err());
+    }
+
+    #[test]
+    fn solves_oversized_races() {
+        let race = Race {
+            duration: 10_000_000_000_000_000_000,
+            distance: 20_000_000_000_000_000_000_000_000_000_000_000_000,
+        };
+        let winning = race.winning_hold_times().unwrap();
+        assert!(race.travelled(*winning.start()) > race.distance);
+        assert!(race.travelled(winning.start() - 1) <= race.distance);
+        assert_eq!(*winning.end(), race.duration - winning.start());
+    }
+
+    #[test]
+    fn solves_part_1_when_the_joined_race_is_too_big() {
+        let example = "\
+Time:      10000000000000000000 1000000000
+Distance:  0 249999999999999999";
+        let input = parse_example(&Day6, example);
+        assert_eq!(Day6.part_1(&input), 9_999_999_999_999_999_999i128.into());
+        assert_eq!(
+            Day6.part_2(&input),
+            Answer::Unsolvable("race is too long to solve".into())
+        );
+    }
+}
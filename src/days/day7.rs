@@ -0,0 +1,742 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::parse_token;
+use crate::{Answer, ParseError, Part, Solution};
+
+/// A type of hand, described by how many of each card it holds, most common
+/// first: a full house is `3-2`. Comparing these puts hands of any size in
+/// the standard order, where more of a kind beats fewer.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandType(Vec<usize>);
+
+/// The names of the standard five card hand types.
+const NAMES: [(&str, &[usize]); 7] = [
+    ("five-of-a-kind", &[5]),
+    ("four-of-a-kind", &[4, 1]),
+    ("full-house", &[3, 2]),
+    ("three-of-a-kind", &[3, 1, 1]),
+    ("two-pair", &[2, 2, 1]),
+    ("one-pair", &[2, 1, 1, 1]),
+    ("high-card", &[1, 1, 1, 1, 1]),
+];
+
+impl HandType {
+    fn from_counts(counts: impl IntoIterator<Item = usize>) -> Self {
+        let mut counts: Vec<usize> = counts.into_iter().filter(|n| *n > 0).collect();
+        counts.sort_by(|a, b| b.cmp(a));
+        HandType(counts)
+    }
+
+    /// Every type of hand of `size` cards, strongest first.
+    pub fn all(size: usize) -> Vec<HandType> {
+        let mut types = vec![];
+        partitions(size, size, &mut vec![], &mut types);
+        types
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.iter().sum()
+    }
+}
+
+/// Pushes every way of splitting `remaining` into parts no bigger than
+/// `largest`, largest parts first, so that the strongest type comes first.
+fn partitions(remaining: usize, largest: usize, parts: &mut Vec<usize>, types: &mut Vec<HandType>) {
+    if remaining == 0 {
+        types.push(HandType(parts.clone()));
+        return;
+    }
+    for part in (1..=largest.min(remaining)).rev() {
+        parts.push(part);
+        partitions(remaining - part, part, parts, types);
+        parts.pop();
+    }
+}
+
+/// Five card types print by name, any others as their counts.
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match NAMES.iter().find(|(_, counts)| *counts == self.0) {
+            Some((name, _)) => f.write_str(name),
+            None => {
+                let counts: Vec<String> = self.0.iter().map(usize::to_string).collect();
+                f.write_str(&counts.join("-"))
+            }
+        }
+    }
+}
+
+/// Parses a hand type written as a name or as counts, like `3-3`.
+fn hand_type(line: &str, token: &str) -> Result<HandType, ParseError> {
+    if let Some((_, counts)) = NAMES.iter().find(|(name, _)| *name == token) {
+        return Ok(HandType(counts.to_vec()));
+    }
+    let counts = token
+        .split('-')
+        .map(|count| count.parse::<usize>().ok().filter(|n| *n > 0))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| ParseError::new(line, token, "unknown hand type"))?;
+    Ok(HandType::from_counts(counts))
+}
+
+/// How a game of camel cards is scored: which cards beat which, which cards are
+/// wild, how many cards make a hand and which types of hand beat which.
+///
+/// Custom rules are written one `key = value` per line. Cards are single
+/// characters, or separated by spaces when some take more than one. Only
+/// `cards` is required; `wild` defaults to none, `size` to five and `types` to
+/// the standard order, strongest first, given by name or as counts:
+///
+/// ```text
+/// # jokers and tens are both wild, and rank lowest
+/// cards = A K Q 9 8 7 6 5 4 3 2 10 J
+/// wild = 10 J
+/// types = five-of-a-kind four-of-a-kind 3-2 three-of-a-kind two-pair one-pair high-card
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Every card, strongest first.
+    cards: Vec<String>,
+    /// Which of `cards` count as whichever card makes the strongest type of hand.
+    wild: Vec<usize>,
+    /// Every type of hand, strongest first.
+    types: Vec<HandType>,
+    /// Whether `types` is the usual ranking, in which a wild card is always
+    /// best played as the most common other card.
+    usual_types: bool,
+}
+
+impl Rules {
+    pub fn standard() -> Self {
+        Rules {
+            cards: "AKQJT98765432".chars().map(String::from).collect(),
+            wild: vec![],
+            types: HandType::all(5),
+            usual_types: true,
+        }
+    }
+
+    /// Jacks become jokers: wild, but the weakest card on their own.
+    pub fn jokers() -> Self {
+        Rules {
+            cards: "AKQT98765432J".chars().map(String::from).collect(),
+            wild: vec![12],
+            types: HandType::all(5),
+            usual_types: true,
+        }
+    }
+
+    /// How many cards make a hand.
+    pub fn size(&self) -> usize {
+        self.types[0].size()
+    }
+
+    /// Splits `dealt`, a subslice of `line`, into cards, taking the longest
+    /// card that matches at each point and skipping whitespace in between.
+    fn cards(&self, line: &str, dealt: &str) -> Result<Vec<usize>, ParseError> {
+        let mut cards = vec![];
+        let mut rest = dealt.trim_start();
+        while let Some(c) = rest.chars().next() {
+            let card = (0..self.cards.len())
+                .filter(|card| rest.starts_with(self.cards[*card].as_str()))
+                .max_by_key(|card| self.cards[*card].len())
+                .ok_or_else(|| ParseError::new(line, &rest[..c.len_utf8()], "unknown card"))?;
+            cards.push(card);
+            rest = rest[self.cards[card].len()..].trim_start();
+        }
+        if cards.len() != self.size() {
+            let message = format!("expected {} cards, found {}", self.size(), cards.len());
+            return Err(ParseError::new(line, dealt, message));
+        }
+        Ok(cards)
+    }
+
+    /// The type of hand `cards` makes, and the cards as played if any wild
+    /// cards had to stand in for others. Under the standard hierarchy adding
+    /// wild cards to the most common card is always best, so only other
+    /// hierarchies need to search.
+    fn hand_type(&self, cards: &[usize]) -> (HandType, Option<Vec<usize>>) {
+        if self.usual_types {
+            self.most_common_hand_type(cards)
+        } else {
+            self.best_hand_type(cards)
+        }
+    }
+
+    /// Plays every wild card as whichever other card is most common, or the
+    /// strongest card if there is a tie.
+    fn most_common_hand_type(&self, cards: &[usize]) -> (HandType, Option<Vec<usize>>) {
+        let mut counts = vec![0; self.cards.len()];
+        let mut wild = 0;
+        for card in cards {
+            if self.wild.contains(card) {
+                wild += 1;
+            } else {
+                counts[*card] += 1;
+            }
+        }
+        let substitute = (0..self.cards.len())
+            .filter(|card| !self.wild.contains(card))
+            .rev()
+            .max_by_key(|card| counts[*card]);
+        let Some(substitute) = substitute else {
+            // every card in the deck is wild, so there is nothing to play them as
+            return (HandType(vec![wild]), None);
+        };
+        counts[substitute] += wild;
+        let played = (wild > 0).then(|| self.play(cards, &vec![substitute; wild]));
+        (HandType::from_counts(counts), played)
+    }
+
+    /// Tries every combination of cards the wild cards could be played as,
+    /// keeping the strongest type of hand.
+    fn best_hand_type(&self, cards: &[usize]) -> (HandType, Option<Vec<usize>>) {
+        let mut candidates: Vec<usize> = vec![];
+        let mut counts: Vec<usize> = vec![];
+        let mut wild = 0;
+        for card in cards {
+            if self.wild.contains(card) {
+                wild += 1;
+                continue;
+            }
+            match candidates.iter().position(|c| c == card) {
+                Some(idx) => counts[idx] += 1,
+                None => {
+                    candidates.push(*card);
+                    counts.push(1);
+                }
+            }
+        }
+        // cards missing from the hand are interchangeable, so one per wild
+        // card covers every way of playing them
+        let fresh: Vec<usize> = (0..self.cards.len())
+            .filter(|card| !self.wild.contains(card) && !candidates.contains(card))
+            .take(wild)
+            .collect();
+        counts.extend(fresh.iter().map(|_| 0));
+        candidates.extend(fresh);
+        if candidates.is_empty() {
+            return (HandType(vec![wild]), None);
+        }
+
+        let mut best = None;
+        search(
+            self,
+            &candidates,
+            0,
+            wild,
+            &mut counts,
+            &mut vec![],
+            &mut best,
+        );
+        let (hand_type, chosen) = best.unwrap();
+        (hand_type, (wild > 0).then(|| self.play(cards, &chosen)))
+    }
+
+    /// Replaces the wild cards in `cards`, in order, with `substitutes`.
+    fn play(&self, cards: &[usize], substitutes: &[usize]) -> Vec<usize> {
+        let mut substitutes = substitutes.iter();
+        cards
+            .iter()
+            .map(|card| {
+                if self.wild.contains(card) {
+                    *substitutes.next().unwrap()
+                } else {
+                    *card
+                }
+            })
+            .collect()
+    }
+
+    /// How strong `hand_type` is under these rules, higher beating lower.
+    fn strength(&self, hand_type: &HandType) -> usize {
+        let position = self.types.iter().position(|t| t == hand_type).unwrap();
+        self.types.len() - position
+    }
+
+    /// Writes `cards` out, with spaces between them if any card needs more
+    /// than one character.
+    fn write(&self, cards: &[usize]) -> String {
+        let names: Vec<&str> = cards
+            .iter()
+            .map(|card| self.cards[*card].as_str())
+            .collect();
+        let single = self.cards.iter().all(|card| card.chars().count() == 1);
+        let separator = if single { "" } else { " " };
+        names.join(separator)
+    }
+}
+
+/// Plays `wild` more wild cards as each of `candidates[from..]` in turn, so that
+/// every combination is tried once, and keeps the strongest hand in `best`.
+fn search(
+    rules: &Rules,
+    candidates: &[usize],
+    from: usize,
+    wild: usize,
+    counts: &mut Vec<usize>,
+    chosen: &mut Vec<usize>,
+    best: &mut Option<(HandType, Vec<usize>)>,
+) {
+    if wild == 0 {
+        let hand_type = HandType::from_counts(counts.iter().copied());
+        let stronger = best
+            .as_ref()
+            .is_none_or(|(best, _)| rules.strength(&hand_type) > rules.strength(best));
+        if stronger {
+            *best = Some((hand_type, chosen.clone()));
+        }
+        return;
+    }
+    for idx in from..candidates.len() {
+        counts[idx] += 1;
+        chosen.push(candidates[idx]);
+        search(rules, candidates, idx, wild - 1, counts, chosen, best);
+        counts[idx] -= 1;
+        chosen.pop();
+    }
+}
+
+/// Splits a `key = value` line, ignoring blank lines and `#` comments.
+fn rule(line: &str) -> Result<Option<(&str, &str)>, ParseError> {
+    let content = line.split('#').next().unwrap_or_default().trim();
+    if content.is_empty() {
+        return Ok(None);
+    }
+    content
+        .split_once('=')
+        .map(|(key, value)| Some((key.trim(), value.trim())))
+        .ok_or_else(|| ParseError::new(line, content, "expected '<rule> = <value>'"))
+}
+
+/// Splits `value`, a subslice of `line`, into distinct cards: one per
+/// character, or separated by whitespace if there is any.
+fn distinct_cards<'a>(line: &str, value: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let tokens: Vec<&str> = if value.contains(char::is_whitespace) {
+        value.split_whitespace().collect()
+    } else {
+        value
+            .char_indices()
+            .map(|(idx, c)| &value[idx..idx + c.len_utf8()])
+            .collect()
+    };
+    for (idx, token) in tokens.iter().enumerate() {
+        if tokens[..idx].contains(token) {
+            return Err(ParseError::new(line, token, "card listed twice"));
+        }
+    }
+    Ok(tokens)
+}
+
+fn hand_types(line: &str, value: &str, size: usize) -> Result<Vec<HandType>, ParseError> {
+    let mut types = vec![];
+    for token in value.split_whitespace() {
+        let hand_type = hand_type(line, token)?;
+        if hand_type.size() != size {
+            let message = format!("expected a hand type of {} cards", size);
+            return Err(ParseError::new(line, token, message));
+        }
+        if types.contains(&hand_type) {
+            return Err(ParseError::new(line, token, "hand type listed twice"));
+        }
+        types.push(hand_type);
+    }
+    if let Some(missing) = HandType::all(size).iter().find(|t| !types.contains(t)) {
+        let message = format!("missing hand type '{}'", missing);
+        return Err(ParseError::at_end(line, message));
+    }
+    Ok(types)
+}
+
+/// The most cards a hand can hold, since every way of splitting a hand into
+/// groups of equal cards is a type of hand to rank.
+const MAX_SIZE: usize = 10;
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cards = vec![];
+        let mut size = 5;
+        // wild cards and hand types can only be checked once every card and
+        // the hand size are known
+        let mut wild = None;
+        let mut types = None;
+        for (i, line) in s.lines().enumerate() {
+            let parsed = rule(line).and_then(|rule| {
+                match rule {
+                    Some(("cards", value)) => cards = distinct_cards(line, value)?,
+                    Some(("wild", value)) => wild = Some((i, line, value)),
+                    Some(("size", value)) => {
+                        size = parse_token(line, value)?;
+                        if size == 0 {
+                            return Err(ParseError::new(line, value, "hands need a card"));
+                        }
+                        if size > MAX_SIZE {
+                            let message = format!("hands hold at most {} cards", MAX_SIZE);
+                            return Err(ParseError::new(line, value, message));
+                        }
+                    }
+                    Some(("types", value)) => types = Some((i, line, value)),
+                    Some((key, _)) => return Err(ParseError::new(line, key, "unknown rule")),
+                    None => {}
+                }
+                Ok(())
+            });
+            parsed.map_err(|err| err.on_line(i + 1))?;
+        }
+        if cards.is_empty() {
+            let last = s.lines().last().unwrap_or_default();
+            let message = "expected 'cards = <cards, strongest first>'";
+            return Err(ParseError::at_end(last, message).on_line(s.lines().count().max(1)));
+        }
+
+        let mut rules = Rules {
+            cards: cards.into_iter().map(String::from).collect(),
+            wild: vec![],
+            types: HandType::all(size),
+            usual_types: true,
+        };
+        if let Some((i, line, value)) = wild {
+            for card in distinct_cards(line, value).map_err(|err| err.on_line(i + 1))? {
+                let idx =
+                    rules.cards.iter().position(|c| c == card).ok_or_else(|| {
+                        ParseError::new(line, card, "unknown card").on_line(i + 1)
+                    })?;
+                rules.wild.push(idx);
+            }
+        }
+        if let Some((i, line, value)) = types {
+            rules.types = hand_types(line, value, size).map_err(|err| err.on_line(i + 1))?;
+            rules.usual_types = rules.types == HandType::all(size);
+        }
+        Ok(rules)
+    }
+}
+
+/// A card, by its rank under the rules it was dealt with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Card(usize);
+
+/// A hand as the rules see it, ordered by the strength of its type and then
+/// by each card in turn.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand {
+    strength: usize,
+    cards: Vec<Card>,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    /// The hand as dealt, for explaining how it was ranked.
+    dealt: String,
+    hand_type: HandType,
+    /// The hand with its wild cards replaced, if it had any.
+    played: Option<String>,
+    hand: Hand,
+    bid: i64,
+}
+
+impl Round {
+    fn new(value: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let (dealt, bid) = value
+            .trim_end()
+            .rsplit_once(char::is_whitespace)
+            .ok_or_else(|| ParseError::at_end(value, "expected '<hand> <bid>'"))?;
+        let dealt = dealt.trim();
+        let cards = rules.cards(value, dealt)?;
+        let (hand_type, played) = rules.hand_type(&cards);
+        let last = rules.cards.len() - 1;
+        Ok(Round {
+            dealt: dealt.to_owned(),
+            played: played.map(|played| rules.write(&played)),
+            hand: Hand {
+                strength: rules.strength(&hand_type),
+                cards: cards.iter().map(|card| Card(last - card)).collect(),
+            },
+            hand_type,
+            bid: parse_token(value, bid)?,
+        })
+    }
+
+    fn explain(&self, rank: usize) -> String {
+        let mut line = format!(
+            "rank {}: {} bid {}, {}",
+            rank, self.dealt, self.bid, self.hand_type
+        );
+        if let Some(played) = &self.played {
+            line.push_str(&format!(" as {}", played));
+        }
+        line
+    }
+}
+
+/// Parses every round, ranking its hand under `rules`.
+pub fn parse(input: &[String], rules: &Rules) -> Result<Vec<Round>, ParseError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| Round::new(x.as_str(), rules).map_err(|err| err.on_line(i + 1)))
+        .collect()
+}
+
+/// The rounds from weakest to strongest hand.
+fn ranked(rounds: &[Round]) -> Vec<&Round> {
+    let mut sorted: Vec<&Round> = rounds.iter().collect();
+    sorted.sort_by(|a, b| a.hand.cmp(&b.hand));
+    sorted
+}
+
+/// Each bid multiplied by the rank of its hand, weakest first, summed.
+pub fn winnings(rounds: &[Round]) -> i64 {
+    ranked(rounds)
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (rank, round)| acc + (rank + 1) as i64 * round.bid)
+}
+
+/// One line per round, weakest first, saying how its hand was ranked.
+pub fn explain(rounds: &[Round]) -> Vec<String> {
+    ranked(rounds)
+        .iter()
+        .enumerate()
+        .map(|(rank, round)| round.explain(rank + 1))
+        .collect()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = (Vec<Round>, Vec<Round>);
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse(lines, &Rules::standard())?,
+            parse(lines, &Rules::jokers())?,
+        ))
+    }
+
+    fn part_1(&self, (rounds, _): &Self::Input) -> Answer {
+        winnings(rounds).into()
+    }
+
+    fn part_2(&self, (_, rounds): &Self::Input) -> Answer {
+        winnings(rounds).into()
+    }
+
+    fn explain(&self, (standard, jokers): &Self::Input, part: Part) -> Vec<String> {
+        match part {
+            Part::One => explain(standard),
+            Part::Two => explain(jokers),
+        }
+    }
+}
+
+/// Day 7 with the rules of one part replaced, as `aoc --rules` does.
+pub struct Variant {
+    pub part: Part,
+    pub rules: Rules,
+}
+
+impl Solution for Variant {
+    const DAY: u8 = 7;
+    type Input = <Day7 as Solution>::Input;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok(match self.part {
+            Part::One => (parse(lines, &self.rules)?, parse(lines, &Rules::jokers())?),
+            Part::Two => (
+                parse(lines, &Rules::standard())?,
+                parse(lines, &self.rules)?,
+            ),
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        Day7.part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        Day7.part_2(input)
+    }
+
+    fn explain(&self, input: &Self::Input, part: Part) -> Vec<String> {
+        Day7.explain(input, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{example_lines, parse_example};
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day7, EXAMPLE);
+        assert_eq!(Day7.part_1(&input), 6440.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day7, EXAMPLE);
+        assert_eq!(Day7.part_2(&input), 5905.into());
+    }
+
+    #[test]
+    fn custom_rules_reproduce_the_jokers() {
+        let rules: Rules = "\
+# the part 2 rules, written out
+cards = AKQT98765432J
+wild = J"
+            .parse()
+            .unwrap();
+        assert_eq!(rules, Rules::jokers());
+    }
+
+    #[test]
+    fn custom_rules_change_the_ranking() {
+        // with tens wild as well, KTJJT becomes five of a kind
+        let rules: Rules = "cards = AKQ98765432TJ\nwild = TJ".parse().unwrap();
+        let rounds = parse(&example_lines(EXAMPLE), &rules).unwrap();
+        assert_eq!(
+            winnings(&rounds),
+            5 * 220 + 4 * 684 + 3 * 483 + 2 * 765 + 28
+        );
+    }
+
+    #[test]
+    fn variant_replaces_the_rules_of_one_part() {
+        let variant = Variant {
+            part: Part::Two,
+            rules: "cards = AKQ98765432TJ\nwild = TJ".parse().unwrap(),
+        };
+        let input = parse_example(&variant, EXAMPLE);
+        assert_eq!(variant.part_1(&input), 6440.into());
+        assert_eq!(
+            variant.part_2(&input),
+            (5 * 220 + 4 * 684 + 3 * 483 + 2 * 765 + 28).into()
+        );
+    }
+
+    #[test]
+    fn rejects_wild_cards_outside_the_deck() {
+        let err = "cards = AKQ\nwild = QJ".parse::<Rules>().unwrap_err();
+        assert_eq!((err.line_number(), err.column()), (2, 9));
+    }
+
+    #[test]
+    fn rejects_hands_too_big_to_rank() {
+        let err = "cards = AKQ\nsize = 100".parse::<Rules>().unwrap_err();
+        assert_eq!((err.line_number(), err.column()), (2, 8));
+        assert_eq!(err.message(), "hands hold at most 10 cards");
+        assert!("cards = AKQ\nsize = 10".parse::<Rules>().is_ok());
+    }
+
+    #[test]
+    fn explains_each_hand() {
+        let (_, jokers) = parse_example(&Day7, EXAMPLE);
+        assert_eq!(
+            explain(&jokers),
+            [
+                "rank 1: 32T3K bid 765, one-pair",
+                "rank 2: KK677 bid 28, two-pair",
+                "rank 3: T55J5 bid 684, four-of-a-kind as T5555",
+                "rank 4: QQQJA bid 483, four-of-a-kind as QQQQA",
+                "rank 5: KTJJT bid 220, four-of-a-kind as KTTTT",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_hands_of_the_wrong_size() {
+        let err = Round::new("KK67 28", &Rules::standard()).unwrap_err();
+        assert_eq!(err.message(), "expected 5 cards, found 4");
+    }
+
+    #[test]
+    fn most_common_card_is_best_for_every_hand() {
+        let two_wild: Rules = "cards = AKQ98765432TJ\nwild = TJ".parse().unwrap();
+        let seven_cards: Rules = "cards = AKQT98765432J\nwild = J\nsize = 7".parse().unwrap();
+        for rules in [Rules::jokers(), two_wild, seven_cards] {
+            // the type only depends on which cards a hand holds, not their
+            // order, so counting up through non-decreasing hands covers them all
+            let mut hand = vec![0; rules.size()];
+            loop {
+                assert_eq!(
+                    rules.most_common_hand_type(&hand).0,
+                    rules.best_hand_type(&hand).0,
+                    "{}",
+                    rules.write(&hand)
+                );
+                let Some(idx) = hand.iter().rposition(|idx| *idx + 1 < rules.cards.len()) else {
+                    break;
+                };
+                hand[idx] += 1;
+                let next = hand[idx];
+                hand[idx..].fill(next);
+            }
+        }
+    }
+
+    #[test]
+    fn searches_wild_cards_under_other_hierarchies() {
+        let rules: Rules = "\
+cards = AKQT98765432J
+wild = J
+types = five-of-a-kind four-of-a-kind full-house two-pair three-of-a-kind one-pair high-card"
+            .parse()
+            .unwrap();
+        let round = Round::new("22J34 1", &rules).unwrap();
+        assert_eq!(
+            (round.hand_type.to_string(), round.played.unwrap()),
+            ("two-pair".to_owned(), "22334".to_owned())
+        );
+        let round = Round::new("22J34 1", &Rules::jokers()).unwrap();
+        assert_eq!(
+            (round.hand_type.to_string(), round.played.unwrap()),
+            ("three-of-a-kind".to_owned(), "22234".to_owned())
+        );
+    }
+
+    #[test]
+    fn ranks_larger_hands_of_multi_character_cards() {
+        let rules: Rules = "cards = A K Q J 10 9 8 7 6 5 4 3 2\nsize = 6"
+            .parse()
+            .unwrap();
+        let rounds = parse(
+            &example_lines("10 10 10 K K K 3\nAAAA23 5\n10AKQJ9 7"),
+            &rules,
+        )
+        .unwrap();
+        let types: Vec<String> = rounds.iter().map(|r| r.hand_type.to_string()).collect();
+        assert_eq!(types, ["3-3", "4-1-1", "1-1-1-1-1-1"]);
+        assert_eq!(winnings(&rounds), 3 * 5 + 2 * 3 + 7);
+    }
+
+    #[test]
+    fn orders_hand_types_by_their_counts() {
+        let names: Vec<String> = HandType::all(5).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            names,
+            [
+                "five-of-a-kind",
+                "four-of-a-kind",
+                "full-house",
+                "three-of-a-kind",
+                "two-pair",
+                "one-pair",
+                "high-card"
+            ]
+        );
+        assert_eq!(HandType::all(7).len(), 15);
+    }
+}
@@ -0,0 +1,1077 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+use num::integer::{ExtendedGcd, Integer};
+
+use crate::json::json_string;
+use crate::parse::{header, parse_token, sections, Section};
+use crate::{Answer, Diagnostic, ParseError, Severity, Solution};
+
+/// The letters instructions use for the first and second successor unless the
+/// input declares an alphabet of its own.
+const LEFT_RIGHT: [char; 2] = ['L', 'R'];
+
+/// Colours for the paths drawn over a network, in turn.
+const PALETTE: [&str; 6] = ["blue", "red", "darkgreen", "darkorange", "purple", "brown"];
+
+/// Index of a node in a [`Graph`].
+pub type NodeId = usize;
+
+/// The network, with node names interned to dense ids when it is parsed.
+/// Successors that are never defined get an id too, so that they can be
+/// reported rather than rejected.
+pub struct Graph {
+    /// The name of each node, by id.
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// The line each node is defined on, or `None` for a name that only ever
+    /// appears as a successor.
+    lines: Vec<Option<usize>>,
+    /// Where the successors of each node start in `successors`, by id, with
+    /// one more entry for where the last node's successors end.
+    offsets: Vec<usize>,
+    /// The successors of every node, one after the other. A node that is never
+    /// defined has none.
+    successors: Vec<NodeId>,
+    /// The letter the instructions use for each successor index, to label
+    /// edges with; empty when the instructions are written as indices.
+    letters: Vec<char>,
+}
+
+impl Graph {
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn line(&self, id: NodeId) -> Option<usize> {
+        self.lines[id]
+    }
+
+    pub fn is_defined(&self, id: NodeId) -> bool {
+        self.lines[id].is_some()
+    }
+
+    pub fn is_start(&self, id: NodeId) -> bool {
+        self.is_defined(id) && self.names[id].ends_with('A')
+    }
+
+    pub fn is_end(&self, id: NodeId) -> bool {
+        self.names[id].ends_with('Z')
+    }
+
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        &self.successors[self.offsets[id]..self.offsets[id + 1]]
+    }
+
+    /// The most successors any one node has.
+    pub fn width(&self) -> usize {
+        (0..self.len())
+            .map(|id| self.successors(id).len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn next(&self, id: NodeId, direction: usize) -> Option<NodeId> {
+        self.successors(id).get(direction).copied()
+    }
+
+    fn intern(&mut self, name: &str, line: Option<usize>) -> NodeId {
+        let id = self.names.len();
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        self.lines.push(line);
+        id
+    }
+
+    /// Renders the network as a Graphviz digraph, with edges labelled by their
+    /// instruction and start and end nodes filled in. Each path is drawn over
+    /// it in its own colour, every edge it takes labelled with the first step
+    /// that took it and how many times it was taken.
+    pub fn to_dot(&self, paths: &[Path]) -> String {
+        let mut out = String::from("digraph network {\n");
+        for (id, name) in self.names.iter().enumerate() {
+            let fill = match (self.is_start(id), self.is_end(id)) {
+                _ if !self.is_defined(id) => ", style=dashed",
+                (true, _) => ", style=filled, fillcolor=palegreen",
+                (_, true) => ", style=filled, fillcolor=lightpink",
+                _ => "",
+            };
+            writeln!(out, "    {} [label={}{}];", id, dot_string(name), fill).unwrap();
+        }
+        for id in 0..self.len() {
+            for (direction, successor) in self.successors(id).iter().enumerate() {
+                let label = self
+                    .letters
+                    .get(direction)
+                    .map_or_else(|| direction.to_string(), char::to_string);
+                writeln!(out, "    {} -> {} [label=\"{}\"];", id, successor, label).unwrap();
+            }
+        }
+        for (path, colour) in paths.iter().zip(PALETTE.iter().cycle()) {
+            // (first step, times taken) for each edge the path takes
+            let mut taken: HashMap<(NodeId, NodeId), (usize, usize)> = HashMap::new();
+            for (step, edge) in path.nodes.windows(2).enumerate() {
+                taken.entry((edge[0], edge[1])).or_insert((step + 1, 0)).1 += 1;
+            }
+            let mut taken: Vec<_> = taken.into_iter().collect();
+            taken.sort_by_key(|(_, (first, _))| *first);
+            for ((from, to), (first, times)) in taken {
+                let label = format!("{}: step {}, taken {}x", path.label, first, times);
+                writeln!(
+                    out,
+                    "    {} -> {} [label={}, color={}, fontcolor={}, penwidth=2];",
+                    from,
+                    to,
+                    dot_string(&label),
+                    colour,
+                    colour
+                )
+                .unwrap();
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Renders the network as JSON: every node with its successors by id, and
+    /// every path as the id of the node it is on at each step. Nodes that are
+    /// never defined have no successors.
+    pub fn to_json(&self, paths: &[Path]) -> String {
+        let mut out = String::from("{\"nodes\":[");
+        for (id, name) in self.names.iter().enumerate() {
+            if id > 0 {
+                out.push(',');
+            }
+            write!(
+                out,
+                "{{\"id\":{},\"name\":{},\"start\":{},\"end\":{},\"successors\":{:?}}}",
+                id,
+                json_string(name),
+                self.is_start(id),
+                self.is_end(id),
+                self.successors(id)
+            )
+            .unwrap();
+        }
+        out.push_str("],\"paths\":[");
+        for (i, path) in paths.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(
+                out,
+                "{{\"label\":{},\"steps\":{:?}}}",
+                json_string(&path.label),
+                path.nodes
+            )
+            .unwrap();
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// One walk through the network, as the node it is on at each step.
+pub struct Path {
+    pub label: String,
+    pub nodes: Vec<NodeId>,
+}
+
+impl Path {
+    /// The path `part_1` follows from `AAA`, up to `ZZZ`, a node that is never
+    /// defined or the point where it starts repeating itself.
+    pub fn part_1(instructions: &[usize], graph: &Graph) -> Option<Path> {
+        let start = defined(graph, "AAA").ok()?;
+        let end = graph.id("ZZZ");
+        let (nodes, _) = trace(instructions, graph, start, |node| Some(node) == end);
+        Some(Path {
+            label: graph.name(start).to_owned(),
+            nodes,
+        })
+    }
+
+    /// The path each ghost in `part_2` follows, up to a node that is never
+    /// defined or the point where it starts repeating itself.
+    pub fn ghosts(instructions: &[usize], graph: &Graph) -> Vec<Path> {
+        (0..graph.len())
+            .filter(|id| graph.is_start(*id))
+            .map(|start| Path {
+                label: graph.name(start).to_owned(),
+                nodes: trace(instructions, graph, start, |_| false).0,
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Section<'_>> for Graph {
+    type Error = ParseError;
+
+    fn try_from(value: Section<'_>) -> Result<Self, Self::Error> {
+        let nodes = value
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                parse_node(line).map_err(|err| err.on_line(i + 1).offset_by(value.offset))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut graph = Graph {
+            names: vec![],
+            ids: HashMap::new(),
+            lines: vec![],
+            offsets: vec![0],
+            successors: vec![],
+            letters: vec![],
+        };
+        // every node needs an id before edges can point at it
+        for (i, (line, (name, _))) in value.lines.iter().zip(&nodes).enumerate() {
+            if graph.ids.contains_key(*name) {
+                return Err(ParseError::new(line, name, "node defined twice")
+                    .on_line(i + 1)
+                    .offset_by(value.offset));
+            }
+            graph.intern(name, Some(value.offset + i + 1));
+        }
+        for (_, targets) in &nodes {
+            for target in targets {
+                let successor = match graph.ids.get(*target) {
+                    Some(successor) => *successor,
+                    None => graph.intern(target, None),
+                };
+                graph.successors.push(successor);
+            }
+            graph.offsets.push(graph.successors.len());
+        }
+        // names that are never defined come last, with no successors
+        graph
+            .offsets
+            .resize(graph.len() + 1, graph.successors.len());
+        Ok(graph)
+    }
+}
+
+fn parse_node(line: &str) -> Result<(&str, Vec<&str>), ParseError> {
+    let (node_name, current_edges) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::at_end(line, "expected '<node> = (<successor>, ...)'"))?;
+    let targets: Vec<&str> = current_edges
+        .strip_prefix('(')
+        .and_then(|edges| edges.strip_suffix(')'))
+        .map(|edges| edges.split(',').map(str::trim).collect())
+        .filter(|targets: &Vec<&str>| targets.iter().all(|target| !target.is_empty()))
+        .ok_or_else(|| ParseError::new(line, current_edges, "expected '(<successor>, ...)'"))?;
+    Ok((node_name, targets))
+}
+
+/// Why a walk through the network has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkError {
+    /// There is no node by this name.
+    Missing(&'static str),
+    /// No node ends in `A`, so there are no ghosts.
+    NoGhosts,
+    /// The walk from the first node reaches the second, which is never defined.
+    Stuck(String, String),
+    /// The walk from the first node reaches the second, which has no successor
+    /// for the instruction it is on.
+    DeadEnd(String, String),
+    /// The walk from this node repeats itself without ever reaching an end.
+    Endless(String),
+    /// Every ghost reaches end nodes, but never all at once.
+    Apart,
+    /// The ghosts only all reach end nodes after more steps than an i128 holds.
+    Overflow,
+}
+
+impl fmt::Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalkError::Missing(name) => write!(f, "there is no node '{}'", name),
+            WalkError::NoGhosts => write!(f, "there is no node ending in 'A'"),
+            WalkError::Stuck(from, to) => write!(
+                f,
+                "the walk from '{}' reaches '{}', which is never defined",
+                from, to
+            ),
+            WalkError::DeadEnd(from, to) => write!(
+                f,
+                "the walk from '{}' reaches '{}', which has too few successors",
+                from, to
+            ),
+            WalkError::Endless(from) => {
+                write!(f, "the walk from '{}' never reaches an end node", from)
+            }
+            WalkError::Apart => write!(f, "the ghosts never all stand on an end node at once"),
+            WalkError::Overflow => write!(
+                f,
+                "the ghosts all stand on an end node only after more steps than a 128-bit integer holds"
+            ),
+        }
+    }
+}
+
+/// Where a walk through the network stopped.
+enum Outcome {
+    /// On a node it was looking for.
+    Found,
+    /// On a node that is never defined, or that has no successor for the next
+    /// instruction, so it can go no further.
+    Stuck,
+    /// Back on the node it was on at the start of an earlier pass through the
+    /// instructions, at this step, so it would repeat itself forever.
+    Repeats(usize),
+}
+
+/// The node a walk from `from` is on at each step, until it is on a node `stop`
+/// accepts, one it cannot go on from, or it starts repeating itself.
+fn trace(
+    instructions: &[usize],
+    graph: &Graph,
+    from: NodeId,
+    stop: impl Fn(NodeId) -> bool,
+) -> (Vec<NodeId>, Outcome) {
+    let len = instructions.len();
+    // a walk repeats once it is back on a node at the same point in the
+    // instructions, so it is enough to look at the start of each pass
+    let mut seen = vec![None; graph.len()];
+    let mut nodes = vec![from];
+    loop {
+        let step = nodes.len() - 1;
+        let node = nodes[step];
+        if step > 0 && stop(node) {
+            return (nodes, Outcome::Found);
+        }
+        if step % len == 0 {
+            if let Some(start) = seen[node] {
+                return (nodes, Outcome::Repeats(start));
+            }
+            seen[node] = Some(step);
+        }
+        match graph.next(node, instructions[step % len]) {
+            Some(next) => nodes.push(next),
+            None => return (nodes, Outcome::Stuck),
+        }
+    }
+}
+
+fn stuck(graph: &Graph, from: NodeId, nodes: &[NodeId]) -> WalkError {
+    let to = nodes[nodes.len() - 1];
+    let (from, name) = (graph.name(from).to_owned(), graph.name(to).to_owned());
+    if graph.is_defined(to) {
+        WalkError::DeadEnd(from, name)
+    } else {
+        WalkError::Stuck(from, name)
+    }
+}
+
+/// The node called `name`, if it is defined.
+fn defined(graph: &Graph, name: &'static str) -> Result<NodeId, WalkError> {
+    graph
+        .id(name)
+        .filter(|id| graph.is_defined(*id))
+        .ok_or(WalkError::Missing(name))
+}
+
+fn part_1(instructions: &[usize], graph: &Graph) -> Result<u64, WalkError> {
+    let start = defined(graph, "AAA")?;
+    let end = graph.id("ZZZ").ok_or(WalkError::Missing("ZZZ"))?;
+    match trace(instructions, graph, start, |node| node == end) {
+        (nodes, Outcome::Found) => Ok(nodes.len() as u64 - 1),
+        (nodes, Outcome::Stuck) => Err(stuck(graph, start, &nodes)),
+        (_, Outcome::Repeats(_)) => Err(WalkError::Endless(graph.name(start).to_owned())),
+    }
+}
+
+/// Every step on which one ghost stands on an end node. Its walk settles into
+/// a cycle, so those are the steps in `tail`, and from `start` on, the steps in
+/// `cycle` plus any multiple of `period`.
+struct Ghost {
+    /// Steps before `start` that end on an end node.
+    tail: Vec<u64>,
+    start: u64,
+    period: u64,
+    /// Steps in `start..start + period` that end on an end node.
+    cycle: Vec<u64>,
+}
+
+impl Ghost {
+    fn walk(instructions: &[usize], graph: &Graph, from: NodeId) -> Result<Self, WalkError> {
+        let (nodes, start) = match trace(instructions, graph, from, |_| false) {
+            (nodes, Outcome::Repeats(start)) => (nodes, start),
+            (nodes, _) => return Err(stuck(graph, from, &nodes)),
+        };
+        let period = nodes.len() - 1 - start;
+        let (tail, cycle) = (0..start + period)
+            .filter(|step| graph.is_end(nodes[*step]))
+            .map(|step| step as u64)
+            .partition(|hit| *hit < start as u64);
+        Ok(Ghost {
+            tail,
+            start: start as u64,
+            period: period as u64,
+            cycle,
+        })
+    }
+
+    fn hits(&self, step: u64) -> bool {
+        if step < self.start {
+            self.tail.contains(&step)
+        } else {
+            let offset = (step - self.start) % self.period;
+            self.cycle.contains(&(self.start + offset))
+        }
+    }
+}
+
+/// Combines `t = a (mod m)` and `t = b (mod n)` into a single congruence, or
+/// `None` if both cannot hold at once.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Result<Option<(i128, i128)>, WalkError> {
+    let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+    if (b - a) % gcd != 0 {
+        return Ok(None);
+    }
+    let lcm = (m / gcd).checked_mul(n).ok_or(WalkError::Overflow)?;
+    // x * m = gcd (mod n), so this many steps of m take a to b; with both
+    // factors below lcm / m and m, nothing here can overflow once lcm fits
+    let k = ((b - a) / gcd * x).rem_euclid(n / gcd);
+    Ok(Some(((a + m * k).rem_euclid(lcm), lcm)))
+}
+
+/// The first step on which every ghost stands on an end node.
+fn earliest_common_step(ghosts: &[Ghost]) -> Result<i128, WalkError> {
+    // a common step before every ghost is in its cycle has to be in the tail
+    // of whichever ghost takes longest to get there
+    let early = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.tail.iter().copied())
+        .filter(|step| ghosts.iter().all(|ghost| ghost.hits(*step)))
+        .min();
+    if let Some(step) = early {
+        return Ok(step as i128);
+    }
+
+    // when every ghost is on an end node exactly at each multiple of its
+    // period, and never before, the answer is where the periods line up
+    if ghosts
+        .iter()
+        .all(|ghost| ghost.tail.is_empty() && ghost.cycle == [ghost.period])
+    {
+        return ghosts.iter().try_fold(1i128, |acc, ghost| {
+            let period = ghost.period as i128;
+            (acc / acc.gcd(&period))
+                .checked_mul(period)
+                .ok_or(WalkError::Overflow)
+        });
+    }
+
+    let mut congruences = vec![(0, 1)];
+    for ghost in ghosts {
+        let period = ghost.period as i128;
+        let mut combined = vec![];
+        for congruence in congruences {
+            for hit in &ghost.cycle {
+                combined.extend(crt(congruence, (*hit as i128, period))?);
+            }
+        }
+        combined.sort();
+        combined.dedup();
+        congruences = combined;
+    }
+    let settled = ghosts.iter().map(|ghost| ghost.start).max().unwrap_or(0) as i128;
+    congruences
+        .into_iter()
+        .map(|(step, modulus)| {
+            if step >= settled {
+                Ok(step)
+            } else {
+                ((settled - step + modulus - 1) / modulus)
+                    .checked_mul(modulus)
+                    .and_then(|skipped| skipped.checked_add(step))
+                    .ok_or(WalkError::Overflow)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .min()
+        .ok_or(WalkError::Apart)
+}
+
+fn part_2(instructions: &[usize], graph: &Graph) -> Result<i128, WalkError> {
+    let mut ghosts = vec![];
+    for start in (0..graph.len()).filter(|id| graph.is_start(*id)) {
+        let ghost = Ghost::walk(instructions, graph, start)?;
+        if ghost.tail.is_empty() && ghost.cycle.is_empty() {
+            return Err(WalkError::Endless(graph.name(start).to_owned()));
+        }
+        ghosts.push(ghost);
+    }
+    if ghosts.is_empty() {
+        return Err(WalkError::NoGhosts);
+    }
+    earliest_common_step(&ghosts)
+}
+
+/// Everything that would stop either part from finding an answer: successors
+/// that are never defined, and walks that get stuck or never reach an end.
+/// Missing `AAA` or `ZZZ` and end nodes no start node leads to are only notes,
+/// since inputs meant for a single part leave them out.
+fn validate(instructions: &[usize], graph: &Graph) -> Vec<Diagnostic> {
+    let warning = |line_number, message| Diagnostic {
+        severity: Severity::Warning,
+        line_number,
+        message,
+    };
+    let note = |line_number, message| Diagnostic {
+        severity: Severity::Note,
+        line_number,
+        message,
+    };
+    let first_line = graph.line(0).unwrap_or(1);
+    let mut diagnostics = vec![];
+
+    for id in (0..graph.len()).filter(|id| graph.is_defined(*id)) {
+        for successor in graph.successors(id) {
+            if !graph.is_defined(*successor) {
+                let message = format!("'{}' is never defined", graph.name(*successor));
+                diagnostics.push(warning(graph.line(id).unwrap(), message));
+            }
+        }
+    }
+
+    match part_1(instructions, graph) {
+        Err(err @ WalkError::Missing(_)) => diagnostics.push(note(first_line, err.to_string())),
+        Err(err) => {
+            let line = graph.line(graph.id("AAA").unwrap()).unwrap();
+            diagnostics.push(warning(line, err.to_string()));
+        }
+        Ok(_) => {}
+    }
+
+    let starts: Vec<NodeId> = (0..graph.len()).filter(|id| graph.is_start(*id)).collect();
+    for start in &starts {
+        let line = graph.line(*start).unwrap();
+        match Ghost::walk(instructions, graph, *start) {
+            Ok(ghost) if ghost.cycle.is_empty() => {
+                let message = format!(
+                    "the walk from '{}' settles into a cycle of {} steps without an end node",
+                    graph.name(*start),
+                    ghost.period
+                );
+                diagnostics.push(warning(line, message));
+            }
+            Ok(_) => {}
+            Err(err) => diagnostics.push(warning(line, err.to_string())),
+        }
+    }
+
+    // which end nodes any start node could ever lead to, whatever the instructions
+    let mut reachable = vec![false; graph.len()];
+    let mut queue = starts;
+    while let Some(id) = queue.pop() {
+        if !std::mem::replace(&mut reachable[id], true) {
+            queue.extend(graph.successors(id));
+        }
+    }
+    for id in (0..graph.len()).filter(|id| graph.is_defined(*id) && graph.is_end(*id)) {
+        if !reachable[id] {
+            let message = format!("no start node leads to '{}'", graph.name(id));
+            diagnostics.push(note(graph.line(id).unwrap(), message));
+        }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line_number);
+    diagnostics.dedup();
+    diagnostics
+}
+
+/// Parses the instructions as successor indices, along with the letters that
+/// stand for them. Letters are `L` and `R` unless an `alphabet: <letters>`
+/// line before the instructions lists others in successor order; instructions
+/// without letters are single digits, or numbers separated by commas or spaces
+/// for networks wider than ten.
+fn parse_instructions(
+    lines: &[String],
+    width: usize,
+) -> Result<(Vec<usize>, Vec<char>), ParseError> {
+    let (alphabet, line) = match lines {
+        [declaration, line] if declaration.starts_with("alphabet") => (
+            Some(parse_alphabet(declaration).map_err(|err| err.on_line(1))?),
+            line,
+        ),
+        [_, extra, ..] => {
+            let message = "expected instructions on a single line";
+            return Err(ParseError::new(extra, extra, message).on_line(2));
+        }
+        [line, ..] => (None, line),
+        [] => unreachable!("sections are never empty"),
+    };
+    let number = lines.len();
+    let line = line.trim();
+    let separator = |c: char| c == ',' || c.is_whitespace();
+    let indices: Vec<&str> = line
+        .split(separator)
+        .filter(|token| !token.is_empty())
+        .collect();
+    let numeric = |token: &str| token.chars().all(|c| c.is_ascii_digit());
+    let (tokens, letters) = match alphabet {
+        Some(alphabet) => (letters(line, &alphabet), alphabet),
+        None if line.contains(separator) && indices.iter().all(|token| numeric(token)) => {
+            let tokens = indices
+                .into_iter()
+                .map(|token| Ok((token, parse_token(line, token)?)))
+                .collect();
+            (tokens, vec![])
+        }
+        None if numeric(line) => {
+            let tokens = line
+                .char_indices()
+                .map(|(idx, c)| Ok((&line[idx..idx + 1], c.to_digit(10).unwrap() as usize)))
+                .collect();
+            (tokens, vec![])
+        }
+        None => (letters(line, &LEFT_RIGHT), LEFT_RIGHT.to_vec()),
+    };
+    let tokens = tokens.map_err(|err| err.on_line(number))?;
+    if tokens.is_empty() {
+        return Err(ParseError::at_end(line, "expected at least one instruction").on_line(number));
+    }
+    let directions = tokens
+        .into_iter()
+        .map(|(token, direction)| {
+            if direction < width {
+                Ok(direction)
+            } else {
+                let message = "no node has that many successors";
+                Err(ParseError::new(line, token, message))
+            }
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|err| err.on_line(number))?;
+    Ok((directions, letters))
+}
+
+/// The letters an `alphabet: <letters>` line declares, in successor order.
+fn parse_alphabet(line: &str) -> Result<Vec<char>, ParseError> {
+    let (key, letters) = header(line)?;
+    if key != "alphabet" {
+        return Err(ParseError::new(line, key, "expected 'alphabet'"));
+    }
+    let mut alphabet = vec![];
+    for (idx, c) in letters.char_indices() {
+        if c == ',' || c.is_whitespace() {
+            continue;
+        }
+        if alphabet.contains(&c) {
+            let token = &letters[idx..idx + c.len_utf8()];
+            return Err(ParseError::new(
+                line,
+                token,
+                "letter appears twice in the alphabet",
+            ));
+        }
+        alphabet.push(c);
+    }
+    if alphabet.is_empty() {
+        return Err(ParseError::at_end(line, "expected at least one letter"));
+    }
+    Ok(alphabet)
+}
+
+/// Each letter of `line` with the index of the successor it picks.
+fn letters<'a>(line: &'a str, alphabet: &[char]) -> Result<Vec<(&'a str, usize)>, ParseError> {
+    line.char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(idx, c)| {
+            let token = &line[idx..idx + c.len_utf8()];
+            match alphabet.iter().position(|letter| *letter == c) {
+                Some(direction) => Ok((token, direction)),
+                None => Err(ParseError::new(line, token, expected(alphabet))),
+            }
+        })
+        .collect()
+}
+
+fn expected(alphabet: &[char]) -> String {
+    match alphabet {
+        [only] => format!("expected '{}'", only),
+        [letters @ .., last] => {
+            let letters: Vec<String> = letters
+                .iter()
+                .map(|letter| format!("'{}'", letter))
+                .collect();
+            format!("expected {} or '{}'", letters.join(", "), last)
+        }
+        [] => unreachable!("alphabets are never empty"),
+    }
+}
+
+fn parse(input: &[String]) -> Result<(Vec<usize>, Graph), ParseError> {
+    let [instructions, network] = sections(input)[..] else {
+        let line = input.first().map(String::as_str).unwrap_or_default();
+        let message = "expected instructions, a blank line, then the network";
+        return Err(ParseError::at_end(line, message).on_line(1));
+    };
+    // instructions that pick a successor no node has are rejected up front
+    let mut graph = Graph::try_from(network)?;
+    let (directions, letters) = parse_instructions(instructions.lines, graph.width())
+        .map_err(|err| err.offset_by(instructions.offset))?;
+    graph.letters = letters;
+    Ok((directions, graph))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = (Vec<usize>, Graph);
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(&input.0, &input.1).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(&input.0, &input.1).into()
+    }
+
+    fn check(&self, input: &Self::Input) -> Vec<Diagnostic> {
+        validate(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_example, parse_example_error};
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const GHOST_EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part_1_example_1() {
+        let input = parse_example(&Day8, EXAMPLE_1);
+        assert_eq!(Day8.part_1(&input), 2.into());
+    }
+
+    #[test]
+    fn part_1_example_2() {
+        let input = parse_example(&Day8, EXAMPLE_2);
+        assert_eq!(Day8.part_1(&input), 6.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day8, GHOST_EXAMPLE);
+        assert_eq!(Day8.part_2(&input), 6.into());
+    }
+
+    /// One ghost per period, each looping back to the start of its cycle from
+    /// its end node, after `lead` steps that are not part of the cycle.
+    fn ghosts_with_periods(periods: &[u64], lead: usize) -> String {
+        let mut example = String::from("L\n");
+        for (ghost, period) in periods.iter().enumerate() {
+            let mut names = vec![format!("Q{}A", ghost)];
+            names.extend((0..lead).map(|step| format!("Q{}L{}", ghost, step)));
+            names.extend((1..*period).map(|step| format!("Q{}N{}", ghost, step)));
+            names.push(format!("Q{}Z", ghost));
+            for pair in names.windows(2) {
+                example.push_str(&format!("\n{} = ({})", pair[0], pair[1]));
+            }
+            let back = &names[lead + 1];
+            example.push_str(&format!("\n{} = ({})", names.last().unwrap(), back));
+        }
+        example
+    }
+
+    #[test]
+    fn counts_ghost_steps_past_a_u64() {
+        let primes = [53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103];
+        let product: i128 = primes.iter().map(|prime| *prime as i128).product();
+        assert!(product > u64::MAX as i128);
+
+        let input = parse_example(&Day8, &ghosts_with_periods(&primes, 0));
+        assert_eq!(part_2(&input.0, &input.1), Ok(product));
+        let input = parse_example(&Day8, &ghosts_with_periods(&primes, 1));
+        assert_eq!(part_2(&input.0, &input.1), Ok(product + 1));
+
+        let more = [
+            53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139,
+            149, 151, 157,
+        ];
+        for lead in [0, 1] {
+            let input = parse_example(&Day8, &ghosts_with_periods(&more, lead));
+            assert_eq!(part_2(&input.0, &input.1), Err(WalkError::Overflow));
+        }
+    }
+
+    #[test]
+    fn interns_node_names() {
+        let (_, graph) = parse_example(&Day8, EXAMPLE_2);
+        assert_eq!(graph.len(), 3);
+        let bbb = graph.id("BBB").unwrap();
+        assert_eq!(graph.name(bbb), "BBB");
+        assert_eq!(graph.name(graph.successors(bbb)[1]), "ZZZ");
+    }
+
+    #[test]
+    fn reports_undefined_successors() {
+        let lines: Vec<String> = EXAMPLE_2
+            .replace("(AAA, ZZZ)", "(AAA, YYY)")
+            .lines()
+            .map(str::to_owned)
+            .collect();
+        let input = Day8.parse(&lines).ok().unwrap();
+        let diagnostics: Vec<String> = Day8.check(&input).iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            diagnostics,
+            [
+                "warning: line 3: the walk from 'AAA' reaches 'YYY', which is never defined",
+                "warning: line 4: 'YYY' is never defined",
+                "note: line 5: no start node leads to 'ZZZ'",
+            ]
+        );
+        assert_eq!(
+            Day8.part_1(&input),
+            Answer::Unsolvable("the walk from 'AAA' reaches 'YYY', which is never defined".into())
+        );
+    }
+
+    #[test]
+    fn reports_walks_that_never_end() {
+        let input = parse_example(&Day8, &EXAMPLE_1.replace("(ZZZ, GGG)", "(GGG, GGG)"));
+        assert_eq!(
+            part_1(&input.0, &input.1),
+            Err(WalkError::Endless("AAA".into()))
+        );
+        assert_eq!(
+            part_2(&input.0, &input.1),
+            Err(WalkError::Endless("AAA".into()))
+        );
+        let diagnostics: Vec<String> = Day8.check(&input).iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            diagnostics,
+            [
+                "warning: line 3: the walk from 'AAA' never reaches an end node",
+                "warning: line 3: the walk from 'AAA' settles into a cycle of 2 steps without an end node",
+                "note: line 9: no start node leads to 'ZZZ'",
+            ]
+        );
+    }
+
+    #[test]
+    fn notes_nodes_missing_for_part_1() {
+        let input = parse_example(&Day8, GHOST_EXAMPLE);
+        let diagnostics: Vec<String> = Day8.check(&input).iter().map(|d| d.to_string()).collect();
+        assert_eq!(diagnostics, ["note: line 3: there is no node 'AAA'"]);
+        assert_eq!(part_1(&input.0, &input.1), Err(WalkError::Missing("AAA")));
+    }
+
+    #[test]
+    fn solves_ghosts_with_tails_and_offsets() {
+        // the first ghost ends on steps 2, 5, 8.., the second on odd steps, so
+        // the periods 3 and 2 alone would give the wrong answer
+        let input = parse_example(
+            &Day8,
+            "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)",
+        );
+        assert_eq!(part_2(&input.0, &input.1), Ok(5));
+    }
+
+    #[test]
+    fn combines_congruences() {
+        assert_eq!(crt((2, 3), (1, 2)), Ok(Some((5, 6))));
+        assert_eq!(crt((0, 4), (2, 6)), Ok(Some((8, 12))));
+        assert_eq!(crt((1, 4), (2, 6)), Ok(None));
+    }
+
+    #[test]
+    fn exports_the_network() {
+        let (instructions, graph) = parse_example(&Day8, EXAMPLE_2);
+        let path = Path::part_1(&instructions, &graph).unwrap();
+        assert_eq!(path.nodes, [0, 1, 0, 1, 0, 1, 2]);
+
+        let dot = graph.to_dot(&[path]);
+        assert!(dot.contains("0 [label=\"AAA\", style=filled, fillcolor=palegreen];"));
+        assert!(dot.contains("1 -> 2 [label=\"R\"];"));
+        assert!(dot.contains("0 -> 1 [label=\"AAA: step 1, taken 3x\", color=blue"));
+
+        let path = Path::part_1(&instructions, &graph).unwrap();
+        assert_eq!(
+            graph.to_json(&[path]),
+            "{\"nodes\":[\
+                {\"id\":0,\"name\":\"AAA\",\"start\":true,\"end\":false,\"successors\":[1, 1]},\
+                {\"id\":1,\"name\":\"BBB\",\"start\":false,\"end\":false,\"successors\":[0, 2]},\
+                {\"id\":2,\"name\":\"ZZZ\",\"start\":false,\"end\":true,\"successors\":[2, 2]}],\
+                \"paths\":[{\"label\":\"AAA\",\"steps\":[0, 1, 0, 1, 0, 1, 2]}]}"
+        );
+    }
+
+    #[test]
+    fn traces_ghosts_until_they_repeat() {
+        let (instructions, graph) = parse_example(&Day8, GHOST_EXAMPLE);
+        let paths = Path::ghosts(&instructions, &graph);
+        let names: Vec<Vec<&str>> = paths
+            .iter()
+            .map(|path| path.nodes.iter().map(|id| graph.name(*id)).collect())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                vec!["11A", "11B", "11Z", "11B", "11Z"],
+                vec!["22A", "22B", "22C", "22Z", "22B", "22C", "22Z", "22B", "22C"],
+            ]
+        );
+    }
+
+    const TERNARY_EXAMPLE: &str = "\
+alphabet: LMR
+LMR
+
+AAA = (B, CC, DDD)
+B = (AAA, AAA, AAA)
+CC = (ZZZ, AAA, AAA)
+DDD = (ZZZ, AAA, AAA)
+ZZZ = (ZZZ, ZZZ, ZZZ)";
+
+    #[test]
+    fn follows_ternary_instructions() {
+        let input = parse_example(&Day8, TERNARY_EXAMPLE);
+        assert_eq!(input.0, [0, 1, 2]);
+        assert_eq!(part_1(&input.0, &input.1), Ok(4));
+        assert_eq!(part_2(&input.0, &input.1), Ok(4));
+
+        let input = parse_example(&Day8, &TERNARY_EXAMPLE.replace("alphabet: LMR\nLMR", "012"));
+        assert_eq!(part_1(&input.0, &input.1), Ok(4));
+    }
+
+    #[test]
+    fn keeps_left_and_right_whatever_the_width_of_the_network() {
+        let example = "\
+LR
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let input = parse_example(&Day8, example);
+        assert_eq!(part_1(&input.0, &input.1), Ok(2));
+
+        let wider = format!("{}\nCCC = (AAA, BBB, ZZZ)", example);
+        let input = parse_example(&Day8, &wider);
+        assert_eq!(input.0, [0, 1]);
+        assert_eq!(part_1(&input.0, &input.1), Ok(2));
+    }
+
+    #[test]
+    fn reads_separated_indices_for_wide_networks() {
+        let mut successors = ["AAA"; 12];
+        successors[11] = "ZZZ";
+        let example = format!("0, 11\n\nAAA = ({})\nZZZ = (ZZZ)", successors.join(", "));
+        let input = parse_example(&Day8, &example);
+        assert_eq!(input.0, [0, 11]);
+        assert_eq!(part_1(&input.0, &input.1), Ok(2));
+        assert_eq!(
+            parse_example(&Day8, &example.replace("0, 11", "0 11")).0,
+            [0, 11]
+        );
+    }
+
+    #[test]
+    fn reads_letters_around_stray_whitespace() {
+        let input = parse_example(&Day8, &EXAMPLE_2.replacen("LLR", "LLR ", 1));
+        assert_eq!(input.0, [0, 0, 1]);
+        let input = parse_example(&Day8, &EXAMPLE_2.replacen("LLR", " L L R", 1));
+        assert_eq!(input.0, [0, 0, 1]);
+    }
+
+    #[test]
+    fn stops_where_a_node_has_too_few_successors() {
+        let input = parse_example(
+            &Day8,
+            &TERNARY_EXAMPLE.replace("B = (AAA, AAA, AAA)", "B = (AAA)"),
+        );
+        assert_eq!(
+            part_1(&input.0, &input.1),
+            Err(WalkError::DeadEnd("AAA".into(), "B".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_instructions_outside_the_alphabet() {
+        let parse = |example: &str| {
+            let err = parse_example_error(&Day8, example);
+            (err.column(), err.message().to_owned())
+        };
+        assert_eq!(
+            parse(&EXAMPLE_2.replace("LLR", "LMR")),
+            (2, "expected 'L' or 'R'".to_owned())
+        );
+        assert_eq!(
+            parse(&TERNARY_EXAMPLE.replace("\nLMR", "\nLXR")),
+            (2, "expected 'L', 'M' or 'R'".to_owned())
+        );
+        assert_eq!(
+            parse(&TERNARY_EXAMPLE.replace("alphabet: LMR", "alphabet: LML")),
+            (13, "letter appears twice in the alphabet".to_owned())
+        );
+        assert_eq!(
+            parse(&EXAMPLE_2.replace("LLR", "012")),
+            (3, "no node has that many successors".to_owned())
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::parse::numbers;
+use crate::{Answer, ParseError, Solution};
+
+fn parse(input: &[String]) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let row = numbers(line, line).map_err(|err| err.on_line(i + 1))?;
+            if row.is_empty() {
+                let message = "expected at least one number";
+                return Err(ParseError::at_end(line, message).on_line(i + 1));
+            }
+            Ok(row)
+        })
+        .collect()
+}
+
+fn expand(row: &[i64]) -> Vec<Vec<i64>> {
+    let mut increases: Vec<Vec<i64>> = vec![row.to_vec()];
+    let mut i = 0;
+    loop {
+        let steps: Vec<i64> = increases[i]
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect();
+        i += 1;
+        increases.push(steps.clone());
+        if steps.iter().all(|step| *step == 0) {
+            break;
+        }
+    }
+    increases
+}
+
+fn part_1(rows: &[Vec<i64>]) -> i64 {
+    rows.iter().fold(0i64, |acc, row| {
+        acc + expand(row)
+            .iter()
+            .rfold(0, |acc, cur| acc + cur.last().unwrap_or(&0))
+    })
+}
+
+fn part_2(rows: &[Vec<i64>]) -> i64 {
+    rows.iter().fold(0i64, |acc, row| {
+        acc + expand(row)
+            .iter()
+            .rfold(0, |acc, cur| cur.first().unwrap_or(&0) - acc)
+    })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_example, parse_example_error};
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn part_1_example() {
+        let input = parse_example(&Day9, EXAMPLE);
+        assert_eq!(Day9.part_1(&input), 114.into());
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = parse_example(&Day9, EXAMPLE);
+        assert_eq!(Day9.part_2(&input), 2.into());
+    }
+
+    #[test]
+    fn rejects_empty_rows() {
+        let err = parse_example_error(&Day9, "0 3 6\n\n");
+        assert_eq!((err.line_number(), err.column()), (2, 1));
+    }
+
+    #[test]
+    fn extrapolates_rows_too_short_to_settle() {
+        let input = parse_example(&Day9, "1 2 4\n7");
+        assert_eq!(Day9.part_1(&input), (7 + 7).into());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Puzzle;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().copied().find(|puzzle| puzzle.day() == day)
+}
@@ -0,0 +1,350 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::{Add, Index, IndexMut};
+
+use crate::ParseError;
+
+/// A position on a grid. Signed, so that stepping off the edge is representable
+/// and can be bounds checked against a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinate {
+    pub fn new(x: i32, y: i32) -> Self {
+        Coordinate { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        let (dx, dy) = direction.offset();
+        Coordinate::new(self.x + dx, self.y + dy)
+    }
+
+    /// The four orthogonally adjacent coordinates, regardless of any bounds.
+    pub fn neighbours_4(self) -> impl Iterator<Item = Coordinate> {
+        Direction::CARDINAL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All eight adjacent coordinates, regardless of any bounds.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Coordinate> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+}
+
+impl Add<Direction> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, direction: Direction) -> Self::Output {
+        self.step(direction)
+    }
+}
+
+/// A compass direction, with north pointing towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    fn index(self) -> usize {
+        Direction::ALL.iter().position(|d| *d == self).unwrap()
+    }
+
+    fn rotated(self, eighths: usize) -> Self {
+        Direction::ALL[(self.index() + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotated(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotated(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotated(4)
+    }
+}
+
+/// A dense, row-major rectangular grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, failing if `f` rejects a character or
+    /// if the lines are not all the same length.
+    pub fn parse_with<E: fmt::Display>(
+        lines: &[String],
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let mut count = 0;
+            for (idx, c) in line.char_indices() {
+                let cell = f(c).map_err(|err| {
+                    ParseError::new(line, &line[idx..idx + c.len_utf8()], err.to_string())
+                        .on_line(i + 1)
+                })?;
+                cells.push(cell);
+                count += 1;
+            }
+            if count != width {
+                let message = format!("expected {} cells, found {}", width, count);
+                return Err(ParseError::at_end(line, message).on_line(i + 1));
+            }
+        }
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        (0..self.width as i32).contains(&coordinate.x)
+            && (0..self.height as i32).contains(&coordinate.y)
+    }
+
+    fn offset(&self, coordinate: Coordinate) -> Option<usize> {
+        self.contains(coordinate)
+            .then(|| coordinate.y as usize * self.width + coordinate.x as usize)
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.offset(coordinate).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        self.offset(coordinate)
+            .map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every coordinate in row-major order.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Coordinate::new(x, y)))
+    }
+
+    /// Every cell alongside its coordinate, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbours of `coordinate` that lie within the grid.
+    pub fn neighbours_4(&self, coordinate: Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        coordinate
+            .neighbours_4()
+            .filter_map(move |c| self.get(c).map(|cell| (c, cell)))
+    }
+
+    /// The orthogonal and diagonal neighbours of `coordinate` that lie within the grid.
+    pub fn neighbours_8(&self, coordinate: Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        coordinate
+            .neighbours_8()
+            .filter_map(move |c| self.get(c).map(|cell| (c, cell)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flatten().cloned().collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let transposed = self.transpose();
+        let cells = transposed
+            .rows()
+            .flat_map(|row| row.iter().rev())
+            .cloned()
+            .collect();
+        Grid::new(transposed.width, transposed.height, cells)
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let transposed = self.transpose();
+        let cells = transposed.rows().rev().flatten().cloned().collect();
+        Grid::new(transposed.width, transposed.height, cells)
+    }
+}
+
+impl TryFrom<&[String]> for Grid<char> {
+    type Error = ParseError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        Grid::parse_with(value, Ok::<_, Infallible>)
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coordinate))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coordinate))
+    }
+}
+
+/// Prints one row per line, with each cell's `Display` output side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        let lines: Vec<String> = rows.iter().map(|row| row.to_string()).collect();
+        Grid::try_from(lines.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn neighbours_stay_within_bounds() {
+        let g = grid(&["abc", "def"]);
+        let corner: Vec<char> = g
+            .neighbours_8(Coordinate::new(0, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(corner, ['b', 'e', 'd']);
+        let middle: Vec<char> = g
+            .neighbours_4(Coordinate::new(1, 1))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(middle, ['b', 'f', 'd']);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!(g.row(1), ['d', 'e', 'f']);
+        let columns: Vec<String> = g.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_clockwise().rotate_counter_clockwise(), g);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let lines = vec!["abc".to_owned(), "de".to_owned()];
+        let err = Grid::try_from(lines.as_slice()).unwrap_err();
+        assert_eq!((err.line_number(), err.column()), (2, 3));
+    }
+
+    #[test]
+    fn turns_between_directions() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_left(), Direction::South);
+        assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+        assert_eq!(
+            Coordinate::new(2, 2) + Direction::NorthWest,
+            Coordinate::new(1, 1)
+        );
+    }
+}
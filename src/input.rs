@@ -0,0 +1,170 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    InvalidUtf8(PathBuf, usize),
+    Empty(PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "no input file at {}", path.display()),
+            InputError::Unreadable(path, err) => {
+                write!(f, "unable to read {}: {}", path.display(), err)
+            }
+            InputError::InvalidUtf8(path, offset) => write!(
+                f,
+                "{} is not valid UTF-8 (first bad byte at offset {})",
+                path.display(),
+                offset
+            ),
+            InputError::Empty(path) => write!(f, "{} is empty", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/dayN`, or `inputs/dayN/input` when the day keeps several input sets.
+    Default,
+    /// A named input set, read from `inputs/dayN/<name>`.
+    Named(String),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument: `-` is stdin, anything that looks
+    /// like a path is a path, and a bare name is a named input set.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else if arg.contains(std::path::is_separator) || Path::new(arg).is_file() {
+            InputSource::Path(PathBuf::from(arg))
+        } else {
+            InputSource::Named(arg.to_owned())
+        }
+    }
+
+    /// The file this source reads for `day`, or `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        self.path_in(Path::new("inputs"), day)
+    }
+
+    fn path_in(&self, inputs: &Path, day: u8) -> Option<PathBuf> {
+        let conventional = inputs.join(format!("day{}", day));
+        match self {
+            InputSource::Default if conventional.is_dir() => Some(conventional.join("input")),
+            InputSource::Default => Some(conventional),
+            InputSource::Named(name) => Some(conventional.join(name)),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn describe(&self, day: u8) -> String {
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => STDIN.to_owned(),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<Vec<String>, InputError> {
+        match self.path(day) {
+            Some(path) => read_lines(path),
+            None => {
+                let mut bytes = vec![];
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| InputError::Unreadable(PathBuf::from(STDIN), err))?;
+                lines(Path::new(STDIN), bytes)
+            }
+        }
+    }
+}
+
+const STDIN: &str = "<stdin>";
+
+pub fn read_lines(path: impl AsRef<Path>) -> Result<Vec<String>, InputError> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_owned()),
+        _ => InputError::Unreadable(path.to_owned(), err),
+    })?;
+    lines(path, bytes)
+}
+
+fn lines(path: &Path, bytes: Vec<u8>) -> Result<Vec<String>, InputError> {
+    let contents = String::from_utf8(bytes)
+        .map_err(|err| InputError::InvalidUtf8(path.to_owned(), err.utf8_error().valid_up_to()))?;
+    if contents.trim().is_empty() {
+        return Err(InputError::Empty(path.to_owned()));
+    }
+    Ok(contents.lines().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interprets_arguments() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("inputs/example.txt"),
+            InputSource::Path(PathBuf::from("inputs/example.txt"))
+        );
+        assert_eq!(
+            InputSource::from_arg("example1"),
+            InputSource::Named("example1".to_owned())
+        );
+    }
+
+    #[test]
+    fn finds_the_default_input_in_a_file_or_a_directory() {
+        let inputs = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(inputs.join("day2")).unwrap();
+        std::fs::write(inputs.join("day1"), "1abc2").unwrap();
+
+        let default = InputSource::Default;
+        assert_eq!(default.path_in(&inputs, 1), Some(inputs.join("day1")));
+        assert_eq!(default.path_in(&inputs, 2), Some(inputs.join("day2/input")));
+        let named = InputSource::Named("example1".to_owned());
+        assert_eq!(
+            named.path_in(&inputs, 2),
+            Some(inputs.join("day2/example1"))
+        );
+        assert_eq!(InputSource::Stdin.path_in(&inputs, 1), None);
+
+        std::fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn rejects_empty_and_invalid_input() {
+        let path = Path::new("day1");
+        assert!(matches!(
+            lines(path, b" \n\n".to_vec()),
+            Err(InputError::Empty(_))
+        ));
+        assert!(matches!(
+            lines(path, b"ab\xffc".to_vec()),
+            Err(InputError::InvalidUtf8(_, 2))
+        ));
+        assert_eq!(lines(path, b"a\nb\n".to_vec()).unwrap(), ["a", "b"]);
+    }
+}
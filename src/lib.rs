@@ -1,13 +1,252 @@
-use std::path::Path;
+use std::fmt;
+
+use bench::Timings;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+mod input;
+mod json;
+pub mod parse;
+
+pub use input::{read_lines, InputError, InputSource};
+pub use parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// The input has no answer, for the reason given.
+    Unsolvable(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unsolvable(reason) => write!(f, "no answer, {}", reason),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+integer_answer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        value.map_or_else(|err| Answer::Unsolvable(err.to_string()), Into::into)
+    }
+}
+
+/// How much a [`Diagnostic`] matters: warnings stop a strict run, notes never do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Note,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Note => write!(f, "note"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Something questionable about an input that a day can nonetheless solve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line_number: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: line {}: {}",
+            self.severity, self.line_number, self.message
+        )
+    }
+}
 
 pub trait Solution {
-    fn solve(&self);
+    const DAY: u8;
+    type Input;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input, ParseError>;
+    fn part_1(&self, input: &Self::Input) -> Answer;
+    fn part_2(&self, input: &Self::Input) -> Answer;
+
+    /// Points out anything questionable in `input` that solving would quietly
+    /// resolve one way or another. Most inputs have nothing to report.
+    fn check(&self, _input: &Self::Input) -> Vec<Diagnostic> {
+        vec![]
+    }
+
+    /// Describes, one line at a time, how `part` arrives at its answer. Most
+    /// days have nothing to add.
+    fn explain(&self, _input: &Self::Input, _part: Part) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input types
+/// can sit side by side in the registry.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn solve(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+    ) -> Result<Vec<(Part, Answer)>, ParseError>;
+    /// Times parsing and each selected part separately over `iterations` runs.
+    fn bench(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<Timings, ParseError>;
+
+    fn check(&self, lines: &[String]) -> Result<Vec<Diagnostic>, ParseError>;
+
+    fn explain(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+    ) -> Result<Vec<(Part, String)>, ParseError>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+    ) -> Result<Vec<(Part, Answer)>, ParseError> {
+        let input = self.parse(lines)?;
+        let mut answers = vec![];
+        if part != Some(Part::Two) {
+            answers.push((Part::One, self.part_1(&input)));
+        }
+        if part != Some(Part::One) {
+            answers.push((Part::Two, self.part_2(&input)));
+        }
+        Ok(answers)
+    }
+
+    fn bench(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<Timings, ParseError> {
+        let (input, parse) = bench::measure(iterations, || self.parse(lines));
+        let input = input?;
+        let mut parts = vec![];
+        if part != Some(Part::Two) {
+            parts.push((
+                Part::One,
+                bench::measure(iterations, || self.part_1(&input)).1,
+            ));
+        }
+        if part != Some(Part::One) {
+            parts.push((
+                Part::Two,
+                bench::measure(iterations, || self.part_2(&input)).1,
+            ));
+        }
+        Ok(Timings { parse, parts })
+    }
+
+    fn check(&self, lines: &[String]) -> Result<Vec<Diagnostic>, ParseError> {
+        let input = self.parse(lines)?;
+        Ok(Solution::check(self, &input))
+    }
+
+    fn explain(
+        &self,
+        lines: &[String],
+        part: Option<Part>,
+    ) -> Result<Vec<(Part, String)>, ParseError> {
+        let input = self.parse(lines)?;
+        let parts = [Part::One, Part::Two]
+            .into_iter()
+            .filter(|p| part.is_none_or(|part| part == *p));
+        Ok(parts
+            .flat_map(|part| {
+                Solution::explain(self, &input, part)
+                    .into_iter()
+                    .map(move |line| (part, line))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn example_lines(example: &str) -> Vec<String> {
+    example.lines().map(String::from).collect()
+}
+
+#[cfg(test)]
+pub(crate) fn parse_example<S: Solution>(solution: &S, example: &str) -> S::Input {
+    solution.parse(&example_lines(example)).unwrap()
 }
 
-pub fn read_lines(path: &str) -> Vec<String> {
-    std::fs::read_to_string(Path::new(path))
-        .expect("Unable to read a file at the path provided")
-        .lines()
-        .map(String::from)
-        .collect()
+/// The error `solution` reports for an example it should reject.
+#[cfg(test)]
+pub(crate) fn parse_example_error<S: Solution>(solution: &S, example: &str) -> ParseError {
+    match solution.parse(&example_lines(example)) {
+        Ok(_) => panic!("expected the example to be rejected"),
+        Err(err) => err,
+    }
 }
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A parse failure pinned to a token within a line of puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line_number: usize,
+    column: usize,
+    line: String,
+    token: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which should be a subslice of `line`. If it
+    /// is not, the error points at the end of the line.
+    pub fn new(line: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= line.len())
+            .unwrap_or(line.len());
+        ParseError {
+            line_number: 0,
+            column: line[..offset].chars().count() + 1,
+            line: line.to_owned(),
+            token: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error pointing just past the end of `line`, for input that
+    /// stops before something required.
+    pub fn at_end(line: &str, message: impl Into<String>) -> Self {
+        ParseError::new(line, &line[line.len()..], message)
+    }
+
+    /// Sets the 1-based line number, for parsers that only ever see one line.
+    pub fn on_line(mut self, line_number: usize) -> Self {
+        self.line_number = line_number;
+        self
+    }
+
+    /// Shifts the line number, for parsers that were handed a slice of the input.
+    pub fn offset_by(mut self, lines: usize) -> Self {
+        self.line_number += lines;
+        self
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.token.chars().count().max(1);
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line_number, self.column, self.message
+        )?;
+        writeln!(f, "    {}", self.line)?;
+        write!(
+            f,
+            "    {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a subslice of `line`, reporting failures against its position.
+pub fn parse_token<T>(line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse()
+        .map_err(|err| ParseError::new(line, token, format!("invalid value '{}': {}", token, err)))
+}
+
+/// Splits a `key: values` line at its first colon, trimming both halves.
+pub fn header(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(':')
+        .map(|(key, values)| (key.trim(), values.trim()))
+        .ok_or_else(|| ParseError::at_end(line, "expected '<key>: <values>'"))
+}
+
+/// Parses every whitespace-separated token of `values`, a subslice of `line`.
+pub fn numbers<T>(line: &str, values: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    values
+        .split_whitespace()
+        .map(|token| parse_token(line, token))
+        .collect()
+}
+
+/// Extracts every signed integer in `line`, skipping whatever separates them.
+pub fn integers(line: &str) -> Result<Vec<i64>, ParseError> {
+    let bytes = line.as_bytes();
+    let mut integers = vec![];
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let negative = bytes[idx] == b'-' && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        idx += 1;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        integers.push(parse_token(line, &line[start..idx])?);
+    }
+    Ok(integers)
+}
+
+/// A run of non-blank lines, remembering where it started in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Number of input lines before this section, for use with [`ParseError::offset_by`].
+    pub offset: usize,
+    pub lines: &'a [String],
+}
+
+/// Splits input into its blank-line-separated sections.
+pub fn sections(lines: &[String]) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start = 0;
+    for (i, line) in lines.iter().chain([&String::new()]).enumerate() {
+        if line.trim().is_empty() {
+            if start < i {
+                sections.push(Section {
+                    offset: start,
+                    lines: &lines[start..i],
+                });
+            }
+            start = i + 1;
+        }
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_caret_under_the_token() {
+        let line = "Card 1: 41 4x 83";
+        let err = numbers::<u8>(line, &line[8..]).unwrap_err().on_line(3);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 12: invalid value '4x': invalid digit found in string\n    \
+             Card 1: 41 4x 83\n               ^^"
+        );
+    }
+
+    #[test]
+    fn splits_headers() {
+        assert_eq!(header("Time:      7  15").unwrap(), ("Time", "7  15"));
+        assert_eq!(header("no colon").unwrap_err().column(), 9);
+    }
+
+    #[test]
+    fn extracts_signed_integers() {
+        let line = "x=-12, y=7..-3 - 4";
+        assert_eq!(integers(line).unwrap(), [-12, 7, -3, 4]);
+    }
+
+    #[test]
+    fn splits_sections_on_blank_lines() {
+        let lines: Vec<String> = ["a", "", "", "b", "c", "  ", "d"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        let sections = sections(&lines);
+        let offsets: Vec<usize> = sections.iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, [0, 3, 6]);
+        assert_eq!(sections[1].lines, ["b", "c"]);
+    }
+}